@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+use crate::latency;
+use crate::scenario::{Scenario, Weights};
+use crate::serving;
+use crate::team;
+
+/// Score change applied on top of the rule or similarity score, with the
+/// reason shown next to the recommendation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Adjustment {
+    pub points: i32,
+    pub reason: String,
+}
+
+impl Adjustment {
+    pub fn new(points: i32, reason: String) -> Self {
+        Adjustment { points, reason }
+    }
+}
+
+/// All adjustments for an architecture in the scenario, in both modes.
+pub fn for_architecture(scenario: &Scenario, weights: &Weights, name: &str) -> Vec<Adjustment> {
+    let mut result = latency::adjust(scenario, weights, name);
+    result.extend(team::adjust(scenario, name));
+    result.extend(serving::adjust(scenario, name));
+    result
+}
+
+pub fn total(scenario: &Scenario, weights: &Weights, name: &str) -> i32 {
+    for_architecture(scenario, weights, name)
+        .iter()
+        .map(|a| a.points)
+        .sum()
+}
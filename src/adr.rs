@@ -0,0 +1,235 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::ahp::CRITERIA;
+use crate::cost::format_money;
+use crate::repl::{explanation, inputs};
+use crate::report::{Report, ReportEntry};
+use crate::scenario::{Scenario, Weights};
+
+/// Number of the next record in `dir`: one past the highest `NNNN-*.md`.
+pub fn next_number(dir: &Path) -> u32 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 1;
+    };
+    entries
+        .filter_map(|e| e.ok()?.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".md"))
+        .filter_map(|name| name.split('-').next()?.parse::<u32>().ok())
+        .max()
+        .map_or(1, |n| n + 1)
+}
+
+/// File-name form of a title: lowercase words joined by dashes.
+pub fn slug(title: &str) -> String {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Today's UTC date as YYYY-MM-DD.
+fn today() -> String {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86_400) as i64;
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn cost_text(report: &Report, entry: &ReportEntry) -> String {
+    match entry.cost {
+        Some(cost) => format!(", ~{}/month", format_money(cost.monthly, &report.currency)),
+        None => String::new(),
+    }
+}
+
+/// Markdown ADR: context from the inputs and weights, the top recommendation
+/// as the decision, the rest of the ranking as alternatives, and the
+/// decision's fit, rule explanations, adjustments and warnings as consequences.
+pub fn render(
+    number: u32,
+    date: &str,
+    report: &Report,
+    scenario: &Scenario,
+    weights: &Weights,
+    method: &str,
+) -> Option<String> {
+    let (decision, alternatives) = report.entries.split_first()?;
+    let mut md = String::new();
+    let _ = writeln!(md, "# {}. Use {}\n", number, decision.name);
+    let _ = writeln!(md, "Date: {}\n", date);
+    let _ = writeln!(md, "## Status\n\nProposed\n");
+
+    let _ = writeln!(md, "## Context\n");
+    let _ = writeln!(
+        md,
+        "Architecture chosen with the Big Data Architecture Advisor ({}).\n",
+        method
+    );
+    let _ = writeln!(md, "Inputs:\n");
+    for (input, value) in inputs(&(scenario.clone(), *weights)) {
+        if value != "-" && !input.starts_with("weight ") {
+            let _ = writeln!(md, "- {}: {}", input, value);
+        }
+    }
+    let _ = writeln!(md, "\nWeights:\n");
+    for (criterion, weight) in CRITERIA.iter().zip(weights.as_array()) {
+        let _ = writeln!(md, "- {}: {}", criterion, weight);
+    }
+
+    let _ = writeln!(md, "\n## Decision\n");
+    let _ = writeln!(
+        md,
+        "We will use **{}** (score {}{}).",
+        decision.name,
+        decision.score,
+        cost_text(report, decision)
+    );
+
+    let _ = writeln!(md, "\n## Considered Alternatives\n");
+    if alternatives.is_empty() {
+        let _ = writeln!(md, "No other architecture matched the inputs.");
+    }
+    let mut listed = vec![decision.name.as_str()];
+    for entry in alternatives {
+        if listed.contains(&entry.name.as_str()) {
+            continue;
+        }
+        listed.push(&entry.name);
+        let _ = writeln!(
+            md,
+            "- {} (score {}{})",
+            entry.name,
+            entry.score,
+            cost_text(report, entry)
+        );
+    }
+
+    let _ = writeln!(md, "\n## Consequences\n");
+    // Only the reasons for the decision, in the fixed order `explanation` gives.
+    for reason in explanation(scenario, weights, &decision.name) {
+        let _ = writeln!(md, "- {}", reason.trim_start_matches("- "));
+    }
+    if !report.warnings.is_empty() {
+        let _ = writeln!(md, "\nRisks:\n");
+        for w in &report.warnings {
+            let _ = writeln!(
+                md,
+                "- [{}] {} Mitigation: {}",
+                w.severity, w.message, w.mitigation
+            );
+        }
+    }
+    Some(md)
+}
+
+/// Writes the next numbered ADR into `dir`, creating it if needed.
+pub fn write(
+    dir: &str,
+    report: &Report,
+    scenario: &Scenario,
+    weights: &Weights,
+    method: &str,
+) -> Result<PathBuf, String> {
+    let dir = Path::new(dir);
+    std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let number = next_number(dir);
+    let md = render(number, &today(), report, scenario, weights, method)
+        .ok_or_else(|| "no recommendation to record".to_string())?;
+    let name = &report.entries[0].name;
+    let path = dir.join(format!("{:04}-use-{}.md", number, slug(name)));
+    std::fs::write(&path, md).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cost::PriceTable;
+    use crate::report::RiskWarning;
+
+    #[test]
+    fn test_slug_and_numbering() {
+        assert_eq!(
+            slug("Managed Kafka (Confluent Cloud/MSK)"),
+            "managed-kafka-confluent-cloud-msk"
+        );
+        let dir = std::env::temp_dir().join(format!("adr-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(next_number(&dir), 1);
+        std::fs::write(dir.join("0001-use-lakehouse.md"), "").unwrap();
+        std::fs::write(dir.join("0007-use-data-mesh.md"), "").unwrap();
+        std::fs::write(dir.join("README.md"), "").unwrap();
+        assert_eq!(next_number(&dir), 8);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(today().len(), 10);
+    }
+
+    #[test]
+    fn test_render_sections() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "high".to_string(),
+            ..Scenario::default()
+        };
+        let weights = Weights::default();
+        let ranked = [("Lakehouse", 120), ("Data Mesh", 100), ("Data Mesh", 95)];
+        let report = Report::new(
+            &ranked,
+            vec!["Masking chosen to enforce column- and row-level policies on PII.".to_string()],
+            &scenario,
+            &weights,
+            &PriceTable::default(),
+        )
+        .with_warnings(vec![RiskWarning {
+            message: "Small teams struggle.".to_string(),
+            severity: "medium".to_string(),
+            mitigation: "Start managed.".to_string(),
+        }]);
+        let md = render(
+            3,
+            "2024-05-01",
+            &report,
+            &scenario,
+            &weights,
+            "strict rules",
+        )
+        .unwrap();
+        let again = render(
+            3,
+            "2024-05-01",
+            &report,
+            &scenario,
+            &weights,
+            "strict rules",
+        );
+        assert_eq!(again.as_deref(), Some(md.as_str()));
+        assert!(md.starts_with("# 3. Use Lakehouse\n"));
+        assert!(md.contains("- volume: large\n"));
+        assert!(md.contains("- SLA: 5\n"));
+        assert!(md.contains("We will use **Lakehouse** (score 120"));
+        assert!(md.contains("## Considered Alternatives\n\n- Data Mesh (score 100"));
+        assert!(!md.contains("score 95"));
+        assert!(md.contains(
+            "- Lakehouse chosen for large mixed workloads, high SLA, sufficient budget.\n"
+        ));
+        assert!(!md.contains("Masking chosen"));
+        assert!(md.contains("- [medium] Small teams struggle. Mitigation: Start managed.\n"));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::scenario::Weights;
+
+/// Criteria in `Weights` order.
+pub const CRITERIA: [&str; 5] = ["SLA", "Budget", "Volume", "Workload", "Observability"];
+
+// Saaty's random consistency index for a 5x5 matrix, and the usual limit
+// above which judgments should be revisited.
+const RANDOM_INDEX: f64 = 1.12;
+pub const CONSISTENCY_LIMIT: f64 = 0.1;
+// Integer weights sum to about the default weight set (5+3+2+1+4).
+const WEIGHT_SCALE: f64 = 15.0;
+const ITERATIONS: usize = 100;
+
+type Matrix = [[f64; 5]; 5];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AhpResult {
+    pub priorities: [f64; 5],
+    pub consistency_ratio: f64,
+    pub weights: Weights,
+}
+
+impl AhpResult {
+    pub fn is_consistent(&self) -> bool {
+        self.consistency_ratio <= CONSISTENCY_LIMIT
+    }
+}
+
+/// The 10 criterion pairs (i, j) with i < j, in the order they are asked.
+pub fn pairs() -> Vec<(usize, usize)> {
+    (0..5)
+        .flat_map(|i| (i + 1..5).map(move |j| (i, j)))
+        .collect()
+}
+
+/// Parses a Saaty-scale judgment: "3" (first is moderately more important),
+/// "1/3" or "0.33" (second is), between 1/9 and 9.
+pub fn parse_judgment(input: &str) -> Option<f64> {
+    let s = input.trim();
+    let value = match s.split_once('/') {
+        Some((num, den)) => num.trim().parse::<f64>().ok()? / den.trim().parse::<f64>().ok()?,
+        None => s.parse::<f64>().ok()?,
+    };
+    (value.is_finite() && (1.0 / 9.0 - 1e-9..=9.0).contains(&value)).then_some(value)
+}
+
+/// Reciprocal comparison matrix from judgments given in `pairs()` order.
+fn matrix_from(judgments: &[f64]) -> Matrix {
+    let mut m = [[1.0; 5]; 5];
+    for (&(i, j), &value) in pairs().iter().zip(judgments) {
+        m[i][j] = value;
+        m[j][i] = 1.0 / value;
+    }
+    m
+}
+
+fn multiply(m: &Matrix, v: &[f64; 5]) -> [f64; 5] {
+    let mut result = [0.0; 5];
+    for (i, row) in m.iter().enumerate() {
+        result[i] = row.iter().zip(v).map(|(a, b)| a * b).sum();
+    }
+    result
+}
+
+fn normalize(v: [f64; 5]) -> [f64; 5] {
+    let sum: f64 = v.iter().sum();
+    v.map(|x| x / sum)
+}
+
+/// Principal eigenvector by power iteration, normalized to sum 1.
+fn priority_vector(m: &Matrix) -> [f64; 5] {
+    let mut v = [0.2; 5];
+    for _ in 0..ITERATIONS {
+        let next = normalize(multiply(m, &v));
+        let delta: f64 = next.iter().zip(&v).map(|(a, b)| (a - b).abs()).sum();
+        v = next;
+        if delta < 1e-12 {
+            break;
+        }
+    }
+    v
+}
+
+fn consistency_ratio(m: &Matrix, priorities: &[f64; 5]) -> f64 {
+    let weighted = multiply(m, priorities);
+    let lambda_max = weighted
+        .iter()
+        .zip(priorities)
+        .map(|(a, w)| a / w)
+        .sum::<f64>()
+        / 5.0;
+    let ci = (lambda_max - 5.0) / 4.0;
+    (ci / RANDOM_INDEX).max(0.0)
+}
+
+/// Weights, priorities and consistency ratio from the 10 pairwise judgments.
+pub fn derive(judgments: &[f64]) -> AhpResult {
+    let m = matrix_from(judgments);
+    let priorities = priority_vector(&m);
+    AhpResult {
+        priorities,
+        consistency_ratio: consistency_ratio(&m, &priorities),
+        weights: Weights::from_array(priorities.map(|p| (p * WEIGHT_SCALE).round() as i32)),
+    }
+}
+
+pub fn print(result: &AhpResult) {
+    println!("\n=== AHP Weights ===");
+    for ((name, p), w) in CRITERIA
+        .iter()
+        .zip(result.priorities)
+        .zip(result.weights.as_array())
+    {
+        println!("- {}: {:.1}% -> weight {}", name, p * 100.0, w);
+    }
+    println!("consistency ratio: {:.3}", result.consistency_ratio);
+    if !result.is_consistent() {
+        println!(
+            "! Inconsistent judgments (CR {:.2} > {:.2}): revisit the comparisons",
+            result.consistency_ratio, CONSISTENCY_LIMIT
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_judgment() {
+        assert_eq!(parse_judgment("3"), Some(3.0));
+        assert_eq!(parse_judgment(" 1/5 "), Some(0.2));
+        assert_eq!(parse_judgment("1/9"), Some(1.0 / 9.0));
+        assert_eq!(parse_judgment("10"), None);
+        assert_eq!(parse_judgment("x"), None);
+        assert_eq!(pairs().len(), 10);
+    }
+
+    #[test]
+    fn test_consistent_judgments_recover_weights() {
+        // Judgments derived exactly from the ratios 5:3:2:1:4.
+        let target = [5.0, 3.0, 2.0, 1.0, 4.0];
+        let judgments: Vec<f64> = pairs()
+            .iter()
+            .map(|&(i, j)| target[i] / target[j])
+            .collect();
+        let result = derive(&judgments);
+        assert!(result.consistency_ratio < 1e-6);
+        assert_eq!(result.weights, Weights::default());
+    }
+
+    #[test]
+    fn test_inconsistent_judgments_flagged() {
+        // SLA > Budget > Volume, but Volume strongly > SLA.
+        let mut judgments = vec![1.0; 10];
+        judgments[0] = 9.0; // SLA vs Budget
+        judgments[1] = 1.0 / 9.0; // SLA vs Volume
+        judgments[4] = 9.0; // Budget vs Volume
+        let result = derive(&judgments);
+        assert!(!result.is_consistent());
+        assert!(derive(&[1.0; 10]).is_consistent());
+    }
+}
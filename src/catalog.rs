@@ -0,0 +1,912 @@
+use crate::compliance::REGIMES;
+use crate::scenario::Scenario;
+use crate::serving;
+use crate::team;
+
+// === Profiles for fuzzy scoring in --multi mode ===
+pub struct ArchProfile {
+    pub name: &'static str,
+    pub volume: &'static str,
+    pub workload: &'static str,
+    pub sla: &'static str,
+    pub budget: &'static str,
+    pub observability: &'static str,
+    pub base: i32,
+}
+
+pub fn profiles() -> Vec<ArchProfile> {
+    vec![
+        ArchProfile {
+            name: "Lakehouse",
+            volume: "large",
+            workload: "mixed",
+            sla: "high",
+            budget: "high",
+            observability: "high",
+            base: 95,
+        },
+        ArchProfile {
+            name: "Kafka/Flink",
+            volume: "any",
+            workload: "streaming",
+            sla: "high",
+            budget: "any",
+            observability: "high",
+            base: 90,
+        },
+        ArchProfile {
+            name: "Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)",
+            volume: "any",
+            workload: "any",
+            sla: "any",
+            budget: "high",
+            observability: "medium",
+            base: 75,
+        },
+        ArchProfile {
+            name: "Data Mesh",
+            volume: "large",
+            workload: "mixed",
+            sla: "high",
+            budget: "any",
+            observability: "high",
+            base: 90,
+        },
+        ArchProfile {
+            name: "ML Platform (Kubeflow/MLflow)",
+            volume: "any",
+            workload: "mixed",
+            sla: "high",
+            budget: "high",
+            observability: "high",
+            base: 88,
+        },
+        ArchProfile {
+            name: "Hybrid Cloud",
+            volume: "large",
+            workload: "mixed",
+            sla: "high",
+            budget: "any",
+            observability: "high",
+            base: 80,
+        },
+        ArchProfile {
+            name: "Data Lake (S3/ADLS)",
+            volume: "large",
+            workload: "batch",
+            sla: "high",
+            budget: "high",
+            observability: "high",
+            base: 75,
+        },
+        ArchProfile {
+            name: "Orchestration (Airflow/Prefect)",
+            volume: "any",
+            workload: "batch",
+            sla: "high",
+            budget: "any",
+            observability: "medium",
+            base: 65,
+        },
+        ArchProfile {
+            name: "Governance Layer (Collibra/Alation)",
+            volume: "large",
+            workload: "any",
+            sla: "high",
+            budget: "any",
+            observability: "high",
+            base: 70,
+        },
+        ArchProfile {
+            name: "Monitoring Stack (Prometheus/Grafana)",
+            volume: "any",
+            workload: "any",
+            sla: "any",
+            budget: "any",
+            observability: "high",
+            base: 85,
+        },
+        ArchProfile {
+            name: "Distributed Tracing (OpenTelemetry/Jaeger)",
+            volume: "any",
+            workload: "streaming",
+            sla: "any",
+            budget: "any",
+            observability: "high",
+            base: 88,
+        },
+        ArchProfile {
+            name: "Data Lineage Tools (OpenLineage/Marquez)",
+            volume: "large",
+            workload: "any",
+            sla: "any",
+            budget: "any",
+            observability: "high",
+            base: 80,
+        },
+        ArchProfile {
+            name: "ELK Stack (Elasticsearch/Logstash/Kibana)",
+            volume: "any",
+            workload: "any",
+            sla: "any",
+            budget: "low",
+            observability: "medium",
+            base: 70,
+        },
+        ArchProfile {
+            name: "Alerting & Incident Response (PagerDuty/OpsGenie)",
+            volume: "any",
+            workload: "any",
+            sla: "high",
+            budget: "any",
+            observability: "high",
+            base: 90,
+        },
+        ArchProfile {
+            name: "FinOps Dashboards",
+            volume: "any",
+            workload: "any",
+            sla: "any",
+            budget: "low",
+            observability: "high",
+            base: 78,
+        },
+        ArchProfile {
+            name: "Hadoop/Spark",
+            volume: "large",
+            workload: "batch",
+            sla: "low",
+            budget: "low",
+            observability: "low",
+            base: 60,
+        },
+        ArchProfile {
+            name: "Data Warehouse",
+            volume: "medium",
+            workload: "batch",
+            sla: "high",
+            budget: "any",
+            observability: "medium",
+            base: 70,
+        },
+        ArchProfile {
+            name: "ETL Pipelines",
+            volume: "any",
+            workload: "batch",
+            sla: "low",
+            budget: "low",
+            observability: "low",
+            base: 50,
+        },
+    ]
+}
+
+/// Architectural layer of a catalog entry, used to order migrations and
+/// to decide which existing component a recommendation replaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Layer {
+    Storage,
+    Ingestion,
+    Processing,
+    MachineLearning,
+    Serving,
+    Orchestration,
+    Governance,
+    Security,
+    Observability,
+}
+
+impl Layer {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Layer::Storage => "storage",
+            Layer::Ingestion => "ingestion",
+            Layer::Processing => "processing",
+            Layer::MachineLearning => "machine learning",
+            Layer::Serving => "serving",
+            Layer::Orchestration => "orchestration",
+            Layer::Governance => "governance",
+            Layer::Security => "security",
+            Layer::Observability => "observability",
+        }
+    }
+
+    /// Layers that must be in place before this one can be migrated.
+    pub fn depends_on(&self) -> &'static [Layer] {
+        match self {
+            Layer::Storage => &[],
+            Layer::Ingestion => &[Layer::Storage],
+            Layer::Processing => &[Layer::Storage],
+            Layer::MachineLearning => &[Layer::Storage, Layer::Processing],
+            Layer::Serving => &[Layer::Storage, Layer::Processing],
+            Layer::Orchestration => &[Layer::Processing],
+            Layer::Governance => &[Layer::Storage],
+            Layer::Security => &[Layer::Storage],
+            Layer::Observability => &[],
+        }
+    }
+
+    /// Whether a scenario runs a single architecture of this layer, so that a
+    /// new one replaces the current one instead of complementing it.
+    pub fn is_exclusive(&self) -> bool {
+        matches!(
+            self,
+            Layer::Storage | Layer::Processing | Layer::Orchestration
+        )
+    }
+}
+
+/// Every architecture known to the rule base or the profiles, with its layer.
+pub const ARCHITECTURES: &[(&str, Layer)] = &[
+    ("Lakehouse", Layer::Storage),
+    ("Data Warehouse", Layer::Storage),
+    ("Cloud DW (BigQuery/Snowflake)", Layer::Storage),
+    ("Data Lake (S3/ADLS)", Layer::Storage),
+    ("Data Mesh", Layer::Storage),
+    ("Hybrid Cloud", Layer::Storage),
+    ("Change Data Capture (Debezium)", Layer::Ingestion),
+    ("Kafka Connect", Layer::Ingestion),
+    ("Managed ELT (Fivetran/Airbyte)", Layer::Ingestion),
+    ("Batch File Drops (SFTP/S3 Landing Zone)", Layer::Ingestion),
+    ("MQTT Bridge (EMQX/HiveMQ)", Layer::Ingestion),
+    ("Log Shippers (Fluent Bit/Vector)", Layer::Ingestion),
+    ("Hadoop/Spark", Layer::Processing),
+    ("Managed Spark (Databricks/EMR)", Layer::Processing),
+    ("Managed Kafka (Confluent Cloud/MSK)", Layer::Processing),
+    (
+        "Micro-batch (Spark Structured Streaming)",
+        Layer::Processing,
+    ),
+    ("Kafka/Flink", Layer::Processing),
+    ("ETL Pipelines", Layer::Processing),
+    ("ML Platform (Kubeflow/MLflow)", Layer::MachineLearning),
+    (
+        "Managed ML Platform (SageMaker/Vertex AI)",
+        Layer::MachineLearning,
+    ),
+    ("Feature Store (Feast/Tecton)", Layer::MachineLearning),
+    ("Model Serving (KServe/Seldon)", Layer::MachineLearning),
+    ("Experiment Tracking (MLflow/W&B)", Layer::MachineLearning),
+    ("GPU Training Cluster (Ray/KubeRay)", Layer::MachineLearning),
+    ("Vector Database (Milvus/pgvector/Pinecone)", Layer::Serving),
+    ("Key-Value Store (Redis/Cassandra/DynamoDB)", Layer::Serving),
+    ("Real-time OLAP (ClickHouse/Druid/Pinot)", Layer::Serving),
+    ("Search Engine (Elasticsearch/OpenSearch)", Layer::Serving),
+    ("Federated SQL (Trino/Presto)", Layer::Serving),
+    ("Orchestration (Airflow/Prefect)", Layer::Orchestration),
+    ("Governance Layer (Collibra/Alation)", Layer::Governance),
+    (
+        "Data Lineage Tools (OpenLineage/Marquez)",
+        Layer::Governance,
+    ),
+    ("Audit Logging (CloudTrail/Audit Vault)", Layer::Governance),
+    ("Schema Registry (Confluent/Apicurio)", Layer::Governance),
+    (
+        "Data Masking & Tokenization (Immuta/Privacera)",
+        Layer::Security,
+    ),
+    ("Encryption & Key Management (KMS/HSM)", Layer::Security),
+    (
+        "Access Control (Apache Ranger/Lake Formation)",
+        Layer::Security,
+    ),
+    ("Network Isolation (VPC/Private Link)", Layer::Security),
+    ("Secrets Management (HashiCorp Vault)", Layer::Security),
+    (
+        "Monitoring Stack (Prometheus/Grafana)",
+        Layer::Observability,
+    ),
+    (
+        "Distributed Tracing (OpenTelemetry/Jaeger)",
+        Layer::Observability,
+    ),
+    (
+        "Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)",
+        Layer::Observability,
+    ),
+    (
+        "ELK Stack (Elasticsearch/Logstash/Kibana)",
+        Layer::Observability,
+    ),
+    (
+        "Alerting & Incident Response (PagerDuty/OpsGenie)",
+        Layer::Observability,
+    ),
+    ("FinOps Dashboards", Layer::Observability),
+    ("Data Validation (Great Expectations)", Layer::Observability),
+    ("Data Quality Monitoring (Soda)", Layer::Observability),
+    ("dbt Tests", Layer::Observability),
+];
+
+/// Regions in which managed services can keep data. Self-hosted
+/// architectures can be deployed anywhere and are not listed.
+pub const MANAGED_REGIONS: &[(&str, &[&str])] = &[
+    (
+        "Managed ELT (Fivetran/Airbyte)",
+        &["eu", "us", "uk", "apac"],
+    ),
+    ("Cloud DW (BigQuery/Snowflake)", &["eu", "us", "uk", "apac"]),
+    ("Data Lake (S3/ADLS)", &["eu", "us", "uk", "apac", "cn"]),
+    (
+        "Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)",
+        &["eu", "us", "uk", "apac", "cn"],
+    ),
+    ("Governance Layer (Collibra/Alation)", &["eu", "us", "apac"]),
+    (
+        "Alerting & Incident Response (PagerDuty/OpsGenie)",
+        &["eu", "us"],
+    ),
+    ("FinOps Dashboards", &["eu", "us"]),
+    ("Data Quality Monitoring (Soda)", &["eu", "us"]),
+    (
+        "Managed Spark (Databricks/EMR)",
+        &["eu", "us", "uk", "apac", "cn"],
+    ),
+    (
+        "Managed Kafka (Confluent Cloud/MSK)",
+        &["eu", "us", "uk", "apac", "cn"],
+    ),
+    (
+        "Managed ML Platform (SageMaker/Vertex AI)",
+        &["eu", "us", "uk", "apac", "cn"],
+    ),
+    (
+        "Data Masking & Tokenization (Immuta/Privacera)",
+        &["eu", "us"],
+    ),
+];
+
+/// End-to-end latency (min, max) in milliseconds that an architecture can
+/// deliver, from data arrival to a queryable result.
+pub const LATENCY_RANGES: &[(&str, f64, f64)] = &[
+    ("Key-Value Store (Redis/Cassandra/DynamoDB)", 1.0, 100.0),
+    ("Kafka/Flink", 50.0, 10_000.0),
+    ("Feature Store (Feast/Tecton)", 5.0, 100.0),
+    ("Model Serving (KServe/Seldon)", 5.0, 500.0),
+    ("Vector Database (Milvus/pgvector/Pinecone)", 5.0, 200.0),
+    ("MQTT Bridge (EMQX/HiveMQ)", 10.0, 1_000.0),
+    ("Change Data Capture (Debezium)", 100.0, 5_000.0),
+    ("Kafka Connect", 100.0, 10_000.0),
+    ("Log Shippers (Fluent Bit/Vector)", 1_000.0, 60_000.0),
+    ("Managed ELT (Fivetran/Airbyte)", 300_000.0, 86_400_000.0),
+    (
+        "Batch File Drops (SFTP/S3 Landing Zone)",
+        900_000.0,
+        86_400_000.0,
+    ),
+    ("Real-time OLAP (ClickHouse/Druid/Pinot)", 1_000.0, 60_000.0),
+    (
+        "Search Engine (Elasticsearch/OpenSearch)",
+        1_000.0,
+        60_000.0,
+    ),
+    (
+        "Micro-batch (Spark Structured Streaming)",
+        1_000.0,
+        900_000.0,
+    ),
+    ("Cloud DW (BigQuery/Snowflake)", 60_000.0, 86_400_000.0),
+    ("Lakehouse", 60_000.0, 86_400_000.0),
+    ("Data Warehouse", 300_000.0, 86_400_000.0),
+    ("Hadoop/Spark", 600_000.0, 86_400_000.0),
+    ("ETL Pipelines", 900_000.0, 86_400_000.0),
+    ("Data Lake (S3/ADLS)", 900_000.0, 86_400_000.0),
+];
+
+/// Typical availability (in %) an architecture reaches in production.
+pub const AVAILABILITY: &[(&str, f64)] = &[
+    ("Key-Value Store (Redis/Cassandra/DynamoDB)", 99.999),
+    ("Cloud DW (BigQuery/Snowflake)", 99.99),
+    ("Data Lake (S3/ADLS)", 99.99),
+    ("Hybrid Cloud", 99.99),
+    ("Lakehouse", 99.95),
+    ("Real-time OLAP (ClickHouse/Druid/Pinot)", 99.95),
+    ("Search Engine (Elasticsearch/OpenSearch)", 99.9),
+    ("Kafka/Flink", 99.95),
+    ("Data Warehouse", 99.9),
+    ("Micro-batch (Spark Structured Streaming)", 99.9),
+    ("Hadoop/Spark", 99.5),
+    ("ETL Pipelines", 99.5),
+];
+
+/// Operational load (0 = fully managed .. 3 = self-managed cluster fleet)
+/// and the skills a team needs to run an architecture.
+pub const OPERATIONS: &[(&str, i32, &[&str])] = &[
+    ("Hadoop/Spark", 3, &["jvm"]),
+    ("Kafka/Flink", 3, &["jvm"]),
+    ("Hybrid Cloud", 3, &["kubernetes"]),
+    ("Data Mesh", 3, &[]),
+    (
+        "ML Platform (Kubeflow/MLflow)",
+        2,
+        &["python", "kubernetes"],
+    ),
+    ("Lakehouse", 2, &["sql", "python"]),
+    ("Micro-batch (Spark Structured Streaming)", 2, &["python"]),
+    ("Key-Value Store (Redis/Cassandra/DynamoDB)", 2, &[]),
+    ("Monitoring Stack (Prometheus/Grafana)", 2, &["kubernetes"]),
+    ("Distributed Tracing (OpenTelemetry/Jaeger)", 2, &[]),
+    ("ELK Stack (Elasticsearch/Logstash/Kibana)", 2, &[]),
+    ("Real-time OLAP (ClickHouse/Druid/Pinot)", 2, &["sql"]),
+    ("Search Engine (Elasticsearch/OpenSearch)", 2, &[]),
+    ("Federated SQL (Trino/Presto)", 2, &["sql", "jvm"]),
+    ("Change Data Capture (Debezium)", 2, &["jvm"]),
+    ("Kafka Connect", 2, &["jvm"]),
+    ("MQTT Bridge (EMQX/HiveMQ)", 2, &[]),
+    ("Log Shippers (Fluent Bit/Vector)", 1, &[]),
+    ("Managed ELT (Fivetran/Airbyte)", 0, &[]),
+    ("Batch File Drops (SFTP/S3 Landing Zone)", 0, &[]),
+    (
+        "GPU Training Cluster (Ray/KubeRay)",
+        3,
+        &["python", "kubernetes"],
+    ),
+    (
+        "Model Serving (KServe/Seldon)",
+        2,
+        &["python", "kubernetes"],
+    ),
+    ("Feature Store (Feast/Tecton)", 2, &["python"]),
+    ("Vector Database (Milvus/pgvector/Pinecone)", 1, &[]),
+    ("Experiment Tracking (MLflow/W&B)", 1, &["python"]),
+    ("Schema Registry (Confluent/Apicurio)", 1, &[]),
+    ("Data Validation (Great Expectations)", 1, &["python"]),
+    ("Data Quality Monitoring (Soda)", 0, &["sql"]),
+    ("dbt Tests", 0, &["sql"]),
+    ("Access Control (Apache Ranger/Lake Formation)", 1, &[]),
+    ("Network Isolation (VPC/Private Link)", 1, &[]),
+    ("Secrets Management (HashiCorp Vault)", 2, &["kubernetes"]),
+    ("Data Warehouse", 1, &["sql"]),
+    ("ETL Pipelines", 1, &["sql"]),
+    ("Orchestration (Airflow/Prefect)", 1, &["python"]),
+    ("Governance Layer (Collibra/Alation)", 1, &[]),
+    ("Data Lineage Tools (OpenLineage/Marquez)", 1, &[]),
+    ("Managed Spark (Databricks/EMR)", 1, &["python"]),
+    ("Managed Kafka (Confluent Cloud/MSK)", 1, &[]),
+    ("Managed ML Platform (SageMaker/Vertex AI)", 1, &["python"]),
+    ("Cloud DW (BigQuery/Snowflake)", 0, &["sql"]),
+    ("Data Lake (S3/ADLS)", 0, &[]),
+    (
+        "Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)",
+        0,
+        &[],
+    ),
+];
+
+/// Data sources the ingestion rules and profiles know.
+pub const SOURCES: &[&str] = &["oltp", "saas", "files", "iot", "logs"];
+
+/// Query patterns a serving architecture answers well: interactive "olap",
+/// point "lookup", full-text "search" and "adhoc" SQL.
+pub const ACCESS_PATTERNS: &[(&str, &[&str])] = &[
+    ("Real-time OLAP (ClickHouse/Druid/Pinot)", &["olap"]),
+    ("Search Engine (Elasticsearch/OpenSearch)", &["search"]),
+    ("Key-Value Store (Redis/Cassandra/DynamoDB)", &["lookup"]),
+    ("Federated SQL (Trino/Presto)", &["adhoc"]),
+    ("Cloud DW (BigQuery/Snowflake)", &["adhoc", "olap"]),
+    ("Data Warehouse", &["adhoc", "olap"]),
+    ("Lakehouse", &["adhoc"]),
+    ("Vector Database (Milvus/pgvector/Pinecone)", &["search"]),
+];
+
+/// Profile that only takes part in --multi ranking when its condition holds,
+/// e.g. compliance components for regulated scenarios.
+pub struct ConditionalProfile {
+    pub profile: ArchProfile,
+    pub applies: fn(&Scenario) -> bool,
+}
+
+fn has_source(scenario: &Scenario, source: &str) -> bool {
+    scenario.sources.iter().any(|s| s == source)
+}
+
+fn has_ml_need(scenario: &Scenario, need: &str) -> bool {
+    scenario.ml_needs.iter().any(|n| n == need)
+}
+
+fn needs_data_quality(scenario: &Scenario) -> bool {
+    matches!(scenario.data_quality_level(), "medium" | "high")
+}
+
+fn has_posture(scenario: &Scenario, posture: &str) -> bool {
+    scenario.security.iter().any(|p| p == posture)
+}
+
+fn has_regime(scenario: &Scenario, regimes: &[&str]) -> bool {
+    scenario
+        .compliance
+        .iter()
+        .any(|c| regimes.contains(&c.as_str()))
+}
+
+pub fn conditional_profiles() -> Vec<ConditionalProfile> {
+    vec![
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Audit Logging (CloudTrail/Audit Vault)",
+                volume: "any",
+                workload: "any",
+                sla: "any",
+                budget: "any",
+                observability: "high",
+                base: 80,
+            },
+            applies: |s| has_regime(s, REGIMES),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Data Masking & Tokenization (Immuta/Privacera)",
+                volume: "any",
+                workload: "any",
+                sla: "any",
+                budget: "high",
+                observability: "any",
+                base: 82,
+            },
+            applies: |s| has_regime(s, &["gdpr", "pci"]) || has_posture(s, "pii"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Encryption & Key Management (KMS/HSM)",
+                volume: "any",
+                workload: "any",
+                sla: "any",
+                budget: "any",
+                observability: "any",
+                base: 85,
+            },
+            applies: |s| {
+                has_regime(s, &["hipaa", "pci"])
+                    || has_posture(s, "pii")
+                    || has_posture(s, "zero-trust")
+            },
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Key-Value Store (Redis/Cassandra/DynamoDB)",
+                volume: "any",
+                workload: "streaming",
+                sla: "high",
+                budget: "any",
+                observability: "any",
+                base: 87,
+            },
+            applies: |s| {
+                s.latency_ms.is_some()
+                    || serving::has_access_patterns(s)
+                    || (has_ml_need(s, "features") && has_ml_need(s, "inference"))
+            },
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Micro-batch (Spark Structured Streaming)",
+                volume: "any",
+                workload: "streaming",
+                sla: "low",
+                budget: "low",
+                observability: "medium",
+                base: 78,
+            },
+            applies: |s| s.latency_ms.is_some(),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Managed Spark (Databricks/EMR)",
+                volume: "large",
+                workload: "batch",
+                sla: "any",
+                budget: "high",
+                observability: "any",
+                base: 82,
+            },
+            applies: team::has_team_input,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Managed Kafka (Confluent Cloud/MSK)",
+                volume: "any",
+                workload: "streaming",
+                sla: "high",
+                budget: "high",
+                observability: "any",
+                base: 85,
+            },
+            applies: team::has_team_input,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Managed ML Platform (SageMaker/Vertex AI)",
+                volume: "any",
+                workload: "mixed",
+                sla: "high",
+                budget: "high",
+                observability: "any",
+                base: 86,
+            },
+            applies: team::has_team_input,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Real-time OLAP (ClickHouse/Druid/Pinot)",
+                volume: "any",
+                workload: "streaming",
+                sla: "high",
+                budget: "any",
+                observability: "any",
+                base: 88,
+            },
+            applies: serving::has_access_patterns,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Search Engine (Elasticsearch/OpenSearch)",
+                volume: "any",
+                workload: "any",
+                sla: "high",
+                budget: "any",
+                observability: "any",
+                base: 84,
+            },
+            applies: serving::has_access_patterns,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Federated SQL (Trino/Presto)",
+                volume: "large",
+                workload: "mixed",
+                sla: "low",
+                budget: "any",
+                observability: "any",
+                base: 80,
+            },
+            applies: serving::has_access_patterns,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Change Data Capture (Debezium)",
+                volume: "any",
+                workload: "streaming",
+                sla: "high",
+                budget: "any",
+                observability: "any",
+                base: 86,
+            },
+            applies: |s| has_source(s, "oltp"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Kafka Connect",
+                volume: "any",
+                workload: "streaming",
+                sla: "any",
+                budget: "any",
+                observability: "any",
+                base: 80,
+            },
+            applies: |s| has_source(s, "oltp") || has_source(s, "iot"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Managed ELT (Fivetran/Airbyte)",
+                volume: "any",
+                workload: "batch",
+                sla: "any",
+                budget: "high",
+                observability: "any",
+                base: 80,
+            },
+            applies: |s| has_source(s, "saas") || has_source(s, "oltp"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Batch File Drops (SFTP/S3 Landing Zone)",
+                volume: "any",
+                workload: "batch",
+                sla: "low",
+                budget: "low",
+                observability: "any",
+                base: 70,
+            },
+            applies: |s| has_source(s, "files"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "MQTT Bridge (EMQX/HiveMQ)",
+                volume: "any",
+                workload: "streaming",
+                sla: "any",
+                budget: "any",
+                observability: "any",
+                base: 84,
+            },
+            applies: |s| has_source(s, "iot"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Log Shippers (Fluent Bit/Vector)",
+                volume: "any",
+                workload: "any",
+                sla: "any",
+                budget: "any",
+                observability: "high",
+                base: 76,
+            },
+            applies: |s| has_source(s, "logs"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Feature Store (Feast/Tecton)",
+                volume: "any",
+                workload: "mixed",
+                sla: "high",
+                budget: "high",
+                observability: "any",
+                base: 84,
+            },
+            applies: |s| has_ml_need(s, "features"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Model Serving (KServe/Seldon)",
+                volume: "any",
+                workload: "streaming",
+                sla: "high",
+                budget: "any",
+                observability: "high",
+                base: 86,
+            },
+            applies: |s| has_ml_need(s, "inference"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Vector Database (Milvus/pgvector/Pinecone)",
+                volume: "any",
+                workload: "any",
+                sla: "high",
+                budget: "any",
+                observability: "any",
+                base: 84,
+            },
+            applies: |s| has_ml_need(s, "retrieval"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Experiment Tracking (MLflow/W&B)",
+                volume: "any",
+                workload: "mixed",
+                sla: "any",
+                budget: "low",
+                observability: "any",
+                base: 78,
+            },
+            applies: |s| has_ml_need(s, "experiments") || has_ml_need(s, "training"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "GPU Training Cluster (Ray/KubeRay)",
+                volume: "large",
+                workload: "batch",
+                sla: "any",
+                budget: "high",
+                observability: "any",
+                base: 84,
+            },
+            applies: |s| has_ml_need(s, "deep-learning"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Data Validation (Great Expectations)",
+                volume: "any",
+                workload: "batch",
+                sla: "any",
+                budget: "low",
+                observability: "high",
+                base: 78,
+            },
+            applies: needs_data_quality,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Data Quality Monitoring (Soda)",
+                volume: "any",
+                workload: "any",
+                sla: "high",
+                budget: "high",
+                observability: "high",
+                base: 82,
+            },
+            applies: needs_data_quality,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "dbt Tests",
+                volume: "any",
+                workload: "batch",
+                sla: "any",
+                budget: "low",
+                observability: "medium",
+                base: 76,
+            },
+            applies: needs_data_quality,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Schema Registry (Confluent/Apicurio)",
+                volume: "any",
+                workload: "streaming",
+                sla: "high",
+                budget: "any",
+                observability: "high",
+                base: 84,
+            },
+            applies: needs_data_quality,
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Access Control (Apache Ranger/Lake Formation)",
+                volume: "any",
+                workload: "any",
+                sla: "high",
+                budget: "any",
+                observability: "any",
+                base: 84,
+            },
+            applies: |s| has_posture(s, "multi-tenant"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Network Isolation (VPC/Private Link)",
+                volume: "any",
+                workload: "any",
+                sla: "high",
+                budget: "any",
+                observability: "any",
+                base: 82,
+            },
+            applies: |s| has_posture(s, "zero-trust"),
+        },
+        ConditionalProfile {
+            profile: ArchProfile {
+                name: "Secrets Management (HashiCorp Vault)",
+                volume: "any",
+                workload: "any",
+                sla: "high",
+                budget: "any",
+                observability: "high",
+                base: 78,
+            },
+            applies: |s| has_posture(s, "zero-trust"),
+        },
+    ]
+}
+
+/// Catalog profiles that take part in --multi ranking for the scenario.
+pub fn profiles_for(scenario: &Scenario) -> Vec<ArchProfile> {
+    let mut result = profiles();
+    result.extend(
+        conditional_profiles()
+            .into_iter()
+            .filter(|c| (c.applies)(scenario))
+            .map(|c| c.profile),
+    );
+    result
+}
+
+pub fn layer_of(name: &str) -> Option<Layer> {
+    ARCHITECTURES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, layer)| *layer)
+}
+
+/// Resolves a user-typed name to a catalog name. Accepts the full name or
+/// the short name before the parenthesis, case-insensitively
+/// (e.g. "elk stack" -> "ELK Stack (Elasticsearch/Logstash/Kibana)").
+pub fn resolve_name(input: &str) -> Option<&'static str> {
+    let wanted = input.trim().to_lowercase();
+    ARCHITECTURES.iter().map(|(n, _)| *n).find(|n| {
+        let full = n.to_lowercase();
+        let short = full.split(" (").next().unwrap_or(&full).to_string();
+        full == wanted || short == wanted
+    })
+}
@@ -0,0 +1,401 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::cost::PriceTable;
+use crate::group::{self, Aggregation};
+use crate::ranking::RankingStrategy;
+use crate::repl::{display, set_input};
+use crate::scenario::{Scenario, ScenarioFile, Weights};
+use crate::MULTI_TOP;
+
+/// One side of a comparison: where it came from and its inputs.
+#[derive(Clone, Debug)]
+pub struct Compared {
+    pub label: String,
+    pub scenario: Scenario,
+    pub weights: Weights,
+}
+
+/// Loads a scenario file, or applies inline `input=value` pairs (separated by
+/// spaces, e.g. "workload=batch budget=low weight.sla=8") to `base`. Without a
+/// base the pairs must set all five core inputs. A file's stakeholder weight
+/// sets are combined with `aggregation`, as `--scenario` does.
+pub fn parse(
+    arg: &str,
+    base: Option<&Compared>,
+    prices: &PriceTable,
+    aggregation: Aggregation,
+) -> Result<Compared, String> {
+    if !arg.contains('=') || Path::new(arg).exists() {
+        let file = ScenarioFile::load(arg)?;
+        let weights = if file.stakeholders.is_empty() {
+            file.weights.unwrap_or_default()
+        } else {
+            group::aggregate_weights(&file.stakeholders, aggregation)
+        };
+        return Ok(Compared {
+            label: arg.to_string(),
+            scenario: file.scenario,
+            weights,
+        });
+    }
+    let (mut scenario, mut weights) = match base {
+        Some(b) => (b.scenario.clone(), b.weights),
+        None => (Scenario::default(), Weights::default()),
+    };
+    for pair in arg.split_whitespace() {
+        let (input, value) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("expected input=value, got {}", pair))?;
+        let input = input.to_lowercase();
+        match input.strip_prefix("weight.") {
+            Some(criterion) => {
+                let mut fields = serde_json::to_value(weights).expect("weights are serializable");
+                let value = value
+                    .parse::<i32>()
+                    .map_err(|_| format!("weight must be a whole number, got {}", value))?;
+                match fields.get_mut(criterion) {
+                    Some(field) => *field = value.into(),
+                    None => return Err(format!("unknown criterion {}", criterion)),
+                }
+                weights = serde_json::from_value(fields).expect("weights round-trip");
+            }
+            None => set_input(&mut scenario, &input, &value.to_lowercase(), prices)?,
+        }
+    }
+    let missing: Vec<&str> = [
+        ("volume", &scenario.volume),
+        ("workload", &scenario.workload),
+        ("sla", &scenario.sla),
+        ("budget", &scenario.budget),
+        ("observability", &scenario.observability),
+    ]
+    .into_iter()
+    .filter(|(_, value)| value.is_empty())
+    .map(|(input, _)| input)
+    .collect();
+    if !missing.is_empty() {
+        return Err(format!("{}: missing {}", arg, missing.join(", ")));
+    }
+    Ok(Compared {
+        label: arg.to_string(),
+        scenario,
+        weights,
+    })
+}
+
+/// Scenario inputs and weights by name ("sla", "weight.sla", ...).
+fn fields(c: &Compared) -> BTreeMap<String, Value> {
+    let mut result = BTreeMap::new();
+    for (prefix, value) in [
+        ("", serde_json::to_value(&c.scenario)),
+        ("weight.", serde_json::to_value(c.weights)),
+    ] {
+        if let Ok(Value::Object(map)) = value {
+            for (key, value) in map {
+                result.insert(format!("{}{}", prefix, key), value);
+            }
+        }
+    }
+    result
+}
+
+fn from_fields(label: &str, fields: &BTreeMap<String, Value>) -> Compared {
+    let (mut scenario, mut weights) = (Map::new(), Map::new());
+    for (key, value) in fields {
+        match key.strip_prefix("weight.") {
+            Some(criterion) => weights.insert(criterion.to_string(), value.clone()),
+            None => scenario.insert(key.clone(), value.clone()),
+        };
+    }
+    Compared {
+        label: label.to_string(),
+        scenario: serde_json::from_value(Value::Object(scenario)).expect("scenario round-trip"),
+        weights: serde_json::from_value(Value::Object(weights)).expect("weights round-trip"),
+    }
+}
+
+/// Inputs whose values differ, as (name, value in `a`, value in `b`).
+pub fn differences(a: &Compared, b: &Compared) -> Vec<(String, String, String)> {
+    let (fa, fb) = (fields(a), fields(b));
+    fb.iter()
+        .filter(|(k, v)| fa.get(*k) != Some(v))
+        .map(|(k, v)| (k.clone(), display(&fa[k]), display(v)))
+        .collect()
+}
+
+/// Ranks scenarios in the selected mode.
+pub struct Comparison<'a> {
+    pub multi: bool,
+    pub strategy: &'a dyn RankingStrategy,
+}
+
+impl Comparison<'_> {
+    fn ranked(&self, c: &Compared) -> Vec<(&'static str, i32)> {
+        crate::rank(&c.scenario, &c.weights, self.multi, self.strategy)
+    }
+
+    fn head(&self, c: &Compared) -> Vec<(&'static str, i32)> {
+        let ranked = self.ranked(c);
+        let n = if self.multi { MULTI_TOP } else { ranked.len() };
+        ranked.into_iter().take(n).collect()
+    }
+
+    fn score(&self, c: &Compared, name: &str) -> Option<i32> {
+        self.ranked(c)
+            .into_iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, s)| s)
+    }
+
+    /// Architectures in either head whose score changed from `base` to
+    /// `other`, with the effect of changing each differing input alone.
+    pub fn changes(&self, base: &Compared, other: &Compared) -> Vec<Change> {
+        let base_fields = fields(base);
+        let other_fields = fields(other);
+        let variants: Vec<(String, Compared)> = differences(base, other)
+            .into_iter()
+            .map(|(key, _, _)| {
+                let mut f = base_fields.clone();
+                f.insert(key.clone(), other_fields[&key].clone());
+                (key.clone(), from_fields(&key, &f))
+            })
+            .collect();
+
+        let (before, after) = (self.ranked(base), self.ranked(other));
+        let mut names: Vec<&'static str> = self.head(base).into_iter().map(|(n, _)| n).collect();
+        for (n, _) in self.head(other) {
+            if !names.contains(&n) {
+                names.push(n);
+            }
+        }
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let find = |ranked: &[(&'static str, i32)]| {
+                    ranked.iter().find(|(n, _)| *n == name).map(|(_, s)| *s)
+                };
+                let (old, new) = (find(&before), find(&after));
+                if old == new {
+                    return None;
+                }
+                let drivers = variants
+                    .iter()
+                    .filter_map(|(key, variant)| {
+                        let effect = effect(old, self.score(variant, name))?;
+                        Some(format!("{} ({})", key, effect))
+                    })
+                    .collect();
+                Some(Change {
+                    name,
+                    old,
+                    new,
+                    drivers,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub name: &'static str,
+    pub old: Option<i32>,
+    pub new: Option<i32>,
+    /// Inputs that move the score on their own, with how.
+    pub drivers: Vec<String>,
+}
+
+fn effect(old: Option<i32>, new: Option<i32>) -> Option<String> {
+    match (old, new) {
+        (Some(a), Some(b)) if a != b => Some(format!("{:+}", b - a)),
+        (None, Some(_)) => Some("adds".to_string()),
+        (Some(_), None) => Some("drops".to_string()),
+        _ => None,
+    }
+}
+
+fn score_text(score: Option<i32>) -> String {
+    score.map_or("-".to_string(), |s| s.to_string())
+}
+
+fn letter(i: usize) -> char {
+    (b'A' + (i % 26) as u8) as char
+}
+
+pub fn print(comparison: &Comparison, scenarios: &[Compared]) {
+    println!("\n=== Scenario Comparison ===");
+    for (i, c) in scenarios.iter().enumerate() {
+        println!("{}: {}", letter(i), c.label);
+    }
+
+    let heads: Vec<_> = scenarios.iter().map(|c| comparison.head(c)).collect();
+    let rows = heads.iter().map(Vec::len).max().unwrap_or(0);
+    let mut header = format!("\n{:<5}", "Rank");
+    for i in 0..scenarios.len() {
+        header.push_str(&format!("{:<48}", letter(i)));
+    }
+    println!("{}", header.trim_end());
+    for row in 0..rows {
+        let mut line = format!("{:<5}", row + 1);
+        for head in &heads {
+            let cell = head
+                .get(row)
+                .map_or(String::new(), |(n, s)| format!("{} ({})", n, s));
+            line.push_str(&format!("{:<48}", cell));
+        }
+        println!("{}", line.trim_end());
+    }
+
+    let base = &scenarios[0];
+    for (i, other) in scenarios.iter().enumerate().skip(1) {
+        println!("\n=== {} vs {} ===", letter(i), letter(0));
+        let diffs: Vec<String> = differences(base, other)
+            .iter()
+            .map(|(k, a, b)| format!("{} {} -> {}", k, a, b))
+            .collect();
+        if diffs.is_empty() {
+            println!("No input differences.");
+            continue;
+        }
+        println!("Input differences: {}", diffs.join("; "));
+        let changes = comparison.changes(base, other);
+        if changes.is_empty() {
+            println!("No score changes.");
+            continue;
+        }
+        println!(
+            "{:<48}{:>6}{:>6}{:>7}  Driven by",
+            "Architecture",
+            letter(0),
+            letter(i),
+            "Delta"
+        );
+        for c in changes {
+            let delta = effect(c.old, c.new).unwrap_or_default();
+            let drivers = if c.drivers.is_empty() {
+                "inputs together".to_string()
+            } else {
+                c.drivers.join(", ")
+            };
+            println!(
+                "{:<48}{:>6}{:>6}{:>7}  {}",
+                c.name,
+                score_text(c.old),
+                score_text(c.new),
+                delta,
+                drivers
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ranking::Additive;
+
+    fn base() -> Compared {
+        Compared {
+            label: "base".to_string(),
+            scenario: Scenario {
+                volume: "large".to_string(),
+                workload: "mixed".to_string(),
+                sla: "high".to_string(),
+                budget: "high".to_string(),
+                observability: "high".to_string(),
+                ..Scenario::default()
+            },
+            weights: Weights::default(),
+        }
+    }
+
+    #[test]
+    fn test_inline_scenario_overrides_base() {
+        let prices = PriceTable::default();
+        let b = parse(
+            "budget=low weight.sla=8 sources=oltp,logs",
+            Some(&base()),
+            &prices,
+            Aggregation::Mean,
+        )
+        .unwrap();
+        assert_eq!(b.scenario.budget, "low");
+        assert_eq!(b.scenario.volume, "large");
+        assert_eq!(b.scenario.sources, vec!["oltp", "logs"]);
+        assert_eq!(b.weights.sla, 8);
+        let diffs = differences(&base(), &b);
+        let keys: Vec<_> = diffs.iter().map(|(k, _, _)| k.as_str()).collect();
+        assert_eq!(keys, vec!["budget", "sources", "weight.sla"]);
+        assert!(parse("colour=blue", Some(&base()), &prices, Aggregation::Mean).is_err());
+        assert!(parse("weight.speed=3", Some(&base()), &prices, Aggregation::Mean).is_err());
+        assert!(parse("sla=hgih", Some(&base()), &prices, Aggregation::Mean).is_err());
+    }
+
+    #[test]
+    fn test_inline_first_scenario_needs_core_inputs() {
+        let prices = PriceTable::default();
+        let err = parse(
+            "workload=batch budget=low",
+            None,
+            &prices,
+            Aggregation::Mean,
+        )
+        .unwrap_err();
+        assert!(err.ends_with("missing volume, sla, observability"));
+        let a = parse(
+            "volume=large workload=mixed sla=high budget=high observability=high",
+            None,
+            &prices,
+            Aggregation::Mean,
+        )
+        .unwrap();
+        assert!(differences(&base(), &a).is_empty());
+    }
+
+    #[test]
+    fn test_file_stakeholders_are_aggregated() {
+        let path = std::env::temp_dir().join(format!("compare-test-{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"volume":"large","workload":"mixed","sla":"high","budget":"high","observability":"high",
+                "stakeholders":[{"name":"CFO","weights":{"sla":1,"budget":9,"volume":1,"workload":1,"observability":1}},
+                                {"name":"CTO","weights":{"sla":9,"budget":1,"volume":1,"workload":1,"observability":1}}]}"#,
+        )
+        .unwrap();
+        let prices = PriceTable::default();
+        let a = parse(path.to_str().unwrap(), None, &prices, Aggregation::Mean).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(a.weights, Weights::from_array([5, 5, 1, 1, 1]));
+    }
+
+    #[test]
+    fn test_changes_name_driving_inputs() {
+        let prices = PriceTable::default();
+        let b = parse(
+            "budget=low weight.volume=3",
+            Some(&base()),
+            &prices,
+            Aggregation::Mean,
+        )
+        .unwrap();
+        let comparison = Comparison {
+            multi: true,
+            strategy: &Additive,
+        };
+        let changes = comparison.changes(&base(), &b);
+        let lakehouse = changes.iter().find(|c| c.name == "Lakehouse").unwrap();
+        // Lakehouse wants a high budget and large volume: the budget drop
+        // costs 3 x 10 points, the extra volume weight adds 10.
+        assert_eq!(lakehouse.new.unwrap() - lakehouse.old.unwrap(), -20);
+        assert_eq!(
+            lakehouse.drivers,
+            vec![
+                "budget (-30)".to_string(),
+                "weight.volume (+10)".to_string()
+            ]
+        );
+    }
+}
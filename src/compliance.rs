@@ -0,0 +1,132 @@
+use crate::catalog::{ARCHITECTURES, MANAGED_REGIONS};
+use crate::scenario::Scenario;
+
+pub const REGIMES: &[&str] = &["gdpr", "hipaa", "pci"];
+
+pub const REGIONS: &[&str] = &["eu", "us", "uk", "apac", "cn"];
+
+fn managed_regions(name: &str) -> Option<&'static [&'static str]> {
+    MANAGED_REGIONS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, regions)| *regions)
+}
+
+/// The scenario's residency region, if it is one the catalog knows.
+pub fn region(scenario: &Scenario) -> Option<&str> {
+    scenario
+        .residency
+        .as_deref()
+        .filter(|r| REGIONS.contains(r))
+}
+
+/// Whether the architecture can keep data in the scenario's residency region.
+pub fn satisfies_residency(name: &str, scenario: &Scenario) -> bool {
+    match (region(scenario), managed_regions(name)) {
+        (Some(region), Some(regions)) => regions.contains(&region),
+        _ => true,
+    }
+}
+
+/// Catalog architectures ruled out by the residency requirement, with the
+/// regions they are available in.
+pub fn excluded(scenario: &Scenario) -> Vec<(&'static str, &'static [&'static str])> {
+    ARCHITECTURES
+        .iter()
+        .filter(|(name, _)| !satisfies_residency(name, scenario))
+        .filter_map(|(name, _)| managed_regions(name).map(|regions| (*name, regions)))
+        .collect()
+}
+
+/// Regimes the rule base knows; only these reach the rules.
+pub fn known_regimes(scenario: &Scenario) -> Vec<&str> {
+    scenario
+        .compliance
+        .iter()
+        .map(|c| c.as_str())
+        .filter(|c| REGIMES.contains(c))
+        .collect()
+}
+
+/// Regimes the rule base does not know about.
+pub fn unknown_regimes(scenario: &Scenario) -> Vec<&str> {
+    scenario
+        .compliance
+        .iter()
+        .map(|c| c.as_str())
+        .filter(|c| !REGIMES.contains(c))
+        .collect()
+}
+
+pub fn print_exclusions(scenario: &Scenario) {
+    for regime in unknown_regimes(scenario) {
+        println!("! Unknown compliance regime ignored: {}", regime);
+    }
+    if let Some(residency) = &scenario.residency {
+        if region(scenario).is_none() {
+            println!(
+                "! Unknown residency region ignored: {} (known: {})",
+                residency,
+                REGIONS.join(", ")
+            );
+        }
+    }
+    let Some(region) = region(scenario) else {
+        return;
+    };
+    let excluded = excluded(scenario);
+    if excluded.is_empty() {
+        return;
+    }
+    println!("\n=== Excluded by data residency ({}) ===", region);
+    for (name, regions) in excluded {
+        println!("x {} (available in: {})", name, regions.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scenario(residency: Option<&str>) -> Scenario {
+        Scenario {
+            residency: residency.map(str::to_string),
+            ..Scenario::default()
+        }
+    }
+
+    #[test]
+    fn test_self_hosted_satisfies_any_region() {
+        assert!(satisfies_residency("Hadoop/Spark", &scenario(Some("cn"))));
+        assert!(satisfies_residency(
+            "Cloud DW (BigQuery/Snowflake)",
+            &scenario(None)
+        ));
+    }
+
+    #[test]
+    fn test_managed_services_excluded_outside_their_regions() {
+        let cn = scenario(Some("cn"));
+        assert!(!satisfies_residency("Cloud DW (BigQuery/Snowflake)", &cn));
+        assert!(satisfies_residency("Data Lake (S3/ADLS)", &cn));
+        let names: Vec<_> = excluded(&cn).into_iter().map(|(n, _)| n).collect();
+        assert!(names.contains(&"Alerting & Incident Response (PagerDuty/OpsGenie)"));
+        assert!(!names.contains(&"Lakehouse"));
+    }
+
+    #[test]
+    fn test_unknown_regimes_and_regions_are_ignored() {
+        let unknown = Scenario {
+            observability: "low".to_string(),
+            compliance: vec!["sox".to_string()],
+            ..scenario(Some("de"))
+        };
+        assert_eq!(region(&unknown), None);
+        assert!(known_regimes(&unknown).is_empty());
+        assert!(excluded(&unknown).is_empty());
+        assert!(satisfies_residency(
+            "Cloud DW (BigQuery/Snowflake)",
+            &unknown
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+// Built-in price table, also the template for custom `--prices` files.
+const DEFAULT_PRICES: &str = include_str!("../prices.json");
+
+const MONTHS_3Y: f64 = 36.0;
+
+/// Parametric monthly cost model of one architecture.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CostModel {
+    #[serde(default)]
+    pub fixed: f64,
+    #[serde(default)]
+    pub per_tb: f64,
+    #[serde(default)]
+    pub per_node: f64,
+    /// Storage handled by a single node; 0 means the node count does not scale with volume.
+    #[serde(default)]
+    pub tb_per_node: f64,
+    #[serde(default)]
+    pub min_nodes: u32,
+    #[serde(default)]
+    pub licensing: f64,
+    /// One-off setup/migration cost, added to the 3-year TCO only.
+    #[serde(default)]
+    pub setup: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct CostEstimate {
+    pub nodes: u32,
+    pub monthly: f64,
+    pub tco_3y: f64,
+}
+
+impl CostModel {
+    pub fn nodes_for(&self, volume_tb: f64) -> u32 {
+        if self.tb_per_node > 0.0 {
+            let scaled = (volume_tb / self.tb_per_node).ceil() as u32;
+            scaled.max(self.min_nodes)
+        } else {
+            self.min_nodes
+        }
+    }
+
+    pub fn estimate(&self, volume_tb: f64) -> CostEstimate {
+        let nodes = self.nodes_for(volume_tb);
+        let monthly =
+            self.fixed + self.per_tb * volume_tb + self.per_node * nodes as f64 + self.licensing;
+        CostEstimate {
+            nodes,
+            monthly,
+            tco_3y: monthly * MONTHS_3Y + self.setup,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PriceTable {
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    /// Monthly amount from which a numeric budget counts as "high".
+    #[serde(default = "default_threshold")]
+    pub high_budget_threshold: f64,
+    #[serde(default)]
+    pub models: HashMap<String, CostModel>,
+    /// Hourly price of each training instance type, by hardware name.
+    #[serde(default)]
+    pub training_hourly: HashMap<String, f64>,
+}
+
+fn default_currency() -> String {
+    "USD".to_string()
+}
+
+fn default_threshold() -> f64 {
+    20000.0
+}
+
+impl Default for PriceTable {
+    fn default() -> Self {
+        serde_json::from_str(DEFAULT_PRICES).expect("built-in prices.json is valid")
+    }
+}
+
+impl PriceTable {
+    /// Loads a price table file on top of the built-in defaults, so a custom
+    /// file only needs to list the architectures whose prices differ.
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        let custom: PriceTable =
+            serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))?;
+        let defaults = PriceTable::default();
+        let mut models = defaults.models;
+        models.extend(custom.models);
+        let mut training_hourly = defaults.training_hourly;
+        training_hourly.extend(custom.training_hourly);
+        Ok(PriceTable {
+            models,
+            training_hourly,
+            ..custom
+        })
+    }
+
+    pub fn estimate(&self, name: &str, volume_tb: f64) -> Option<CostEstimate> {
+        self.models.get(name).map(|m| m.estimate(volume_tb))
+    }
+}
+
+/// Formats an amount with thousands separators, e.g. "12,345 USD".
+pub fn format_money(amount: f64, currency: &str) -> String {
+    let whole = amount.round() as i64;
+    let digits = whole.abs().to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    let sign = if whole < 0 { "-" } else { "" };
+    format!("{}{} {}", sign, grouped, currency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_scales_nodes_with_volume() {
+        let model = CostModel {
+            fixed: 1000.0,
+            per_tb: 10.0,
+            per_node: 500.0,
+            tb_per_node: 20.0,
+            min_nodes: 3,
+            licensing: 200.0,
+            setup: 5000.0,
+        };
+        let small = model.estimate(10.0);
+        assert_eq!(small.nodes, 3);
+        assert_eq!(small.monthly, 1000.0 + 100.0 + 1500.0 + 200.0);
+        let large = model.estimate(200.0);
+        assert_eq!(large.nodes, 10);
+        assert_eq!(large.tco_3y, large.monthly * 36.0 + 5000.0);
+    }
+
+    #[test]
+    fn test_default_table_covers_catalog() {
+        let table = PriceTable::default();
+        for (name, _) in crate::catalog::ARCHITECTURES {
+            assert!(table.estimate(name, 1.0).is_some(), "{}", name);
+        }
+        for hw in crate::ml::HARDWARE {
+            assert!(table.training_hourly.contains_key(hw.name), "{}", hw.name);
+        }
+    }
+
+    #[test]
+    fn test_format_money() {
+        assert_eq!(format_money(1234567.4, "USD"), "1,234,567 USD");
+        assert_eq!(format_money(999.0, "EUR"), "999 EUR");
+    }
+}
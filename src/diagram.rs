@@ -0,0 +1,288 @@
+use std::fmt::Write;
+
+use crate::catalog::{layer_of, Layer};
+use crate::scenario::Scenario;
+
+/// Column of the diagram. The first five carry data from left to right;
+/// observability and governance watch over them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Sources,
+    Ingestion,
+    Storage,
+    Processing,
+    Serving,
+    Observability,
+    Governance,
+}
+
+const PIPELINE: [Stage; 5] = [
+    Stage::Sources,
+    Stage::Ingestion,
+    Stage::Storage,
+    Stage::Processing,
+    Stage::Serving,
+];
+
+impl Stage {
+    fn id(&self) -> &'static str {
+        match self {
+            Stage::Sources => "sources",
+            Stage::Ingestion => "ingestion",
+            Stage::Storage => "storage",
+            Stage::Processing => "processing",
+            Stage::Serving => "serving",
+            Stage::Observability => "observability",
+            Stage::Governance => "governance",
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            Stage::Sources => "Sources",
+            Stage::Ingestion => "Ingestion",
+            Stage::Storage => "Storage",
+            Stage::Processing => "Processing",
+            Stage::Serving => "Serving",
+            Stage::Observability => "Observability",
+            Stage::Governance => "Governance & Security",
+        }
+    }
+
+    fn of(layer: Layer) -> Stage {
+        match layer {
+            Layer::Storage => Stage::Storage,
+            Layer::Ingestion => Stage::Ingestion,
+            Layer::Processing | Layer::MachineLearning | Layer::Orchestration => Stage::Processing,
+            Layer::Serving => Stage::Serving,
+            Layer::Observability => Stage::Observability,
+            Layer::Governance | Layer::Security => Stage::Governance,
+        }
+    }
+}
+
+fn source_label(source: &str) -> String {
+    match source {
+        "oltp" => "OLTP databases".to_string(),
+        "saas" => "SaaS APIs".to_string(),
+        "files" => "File drops".to_string(),
+        "iot" => "IoT devices".to_string(),
+        "logs" => "Application logs".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Recommended components grouped by stage, empty stages left out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagram {
+    pub stages: Vec<(Stage, Vec<String>)>,
+}
+
+impl Diagram {
+    /// Diagram of the recommended architectures and the scenario's sources.
+    pub fn build(scenario: &Scenario, recommended: &[&str]) -> Self {
+        let mut stages: Vec<(Stage, Vec<String>)> = vec![];
+        let mut add =
+            |stage: Stage, name: String| match stages.iter_mut().find(|(s, _)| *s == stage) {
+                Some((_, names)) if names.contains(&name) => {}
+                Some((_, names)) => names.push(name),
+                None => stages.push((stage, vec![name])),
+            };
+        if scenario.sources.is_empty() {
+            add(Stage::Sources, "Data sources".to_string());
+        }
+        for source in &scenario.sources {
+            add(Stage::Sources, source_label(source));
+        }
+        for name in recommended {
+            let stage = layer_of(name).map_or(Stage::Processing, Stage::of);
+            add(stage, name.to_string());
+        }
+        stages.sort_by_key(|(stage, _)| *stage);
+        Diagram { stages }
+    }
+
+    fn has(&self, stage: Stage) -> bool {
+        self.stages.iter().any(|(s, _)| *s == stage)
+    }
+
+    /// Consecutive pipeline stages present in the diagram.
+    fn flows(&self) -> Vec<(Stage, Stage)> {
+        let present: Vec<Stage> = PIPELINE.into_iter().filter(|s| self.has(*s)).collect();
+        present.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+
+    /// Pipeline stages reporting to observability.
+    fn monitored(&self) -> Vec<Stage> {
+        if !self.has(Stage::Observability) {
+            return vec![];
+        }
+        PIPELINE[1..]
+            .iter()
+            .copied()
+            .filter(|s| self.has(*s))
+            .collect()
+    }
+
+    fn governed(&self) -> Option<Stage> {
+        if !self.has(Stage::Governance) {
+            return None;
+        }
+        [Stage::Storage, Stage::Processing, Stage::Ingestion]
+            .into_iter()
+            .find(|s| self.has(*s))
+    }
+
+    /// Node ids ("storage0", ...) and labels per stage.
+    fn nodes(&self) -> Vec<(Stage, Vec<(String, &str)>)> {
+        self.stages
+            .iter()
+            .map(|(stage, names)| {
+                let nodes = names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| (format!("{}{}", stage.id(), i), name.as_str()))
+                    .collect();
+                (*stage, nodes)
+            })
+            .collect()
+    }
+
+    /// Mermaid flowchart with one subgraph per stage.
+    pub fn mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+        for (stage, nodes) in self.nodes() {
+            let _ = writeln!(out, "    subgraph {}[\"{}\"]", stage.id(), stage.title());
+            for (id, label) in nodes {
+                let _ = writeln!(out, "        {}[\"{}\"]", id, label.replace('"', "#quot;"));
+            }
+            let _ = writeln!(out, "    end");
+        }
+        for (from, to) in self.flows() {
+            let _ = writeln!(out, "    {} --> {}", from.id(), to.id());
+        }
+        for stage in self.monitored() {
+            let _ = writeln!(out, "    {} -. telemetry .-> observability", stage.id());
+        }
+        if let Some(stage) = self.governed() {
+            let _ = writeln!(out, "    governance -. governs .- {}", stage.id());
+        }
+        out
+    }
+
+    /// Graphviz DOT graph with one cluster per stage. Edges join the first
+    /// nodes of two clusters and are clipped to the cluster borders.
+    pub fn graphviz(&self) -> String {
+        let mut out = String::from(
+            "digraph architecture {\n    rankdir=LR;\n    compound=true;\n    node [shape=box];\n",
+        );
+        for (stage, nodes) in self.nodes() {
+            let _ = writeln!(out, "    subgraph cluster_{} {{", stage.id());
+            let _ = writeln!(out, "        label=\"{}\";", stage.title());
+            for (id, label) in nodes {
+                let _ = writeln!(
+                    out,
+                    "        {} [label=\"{}\"];",
+                    id,
+                    label.replace('"', "\\\"")
+                );
+            }
+            let _ = writeln!(out, "    }}");
+        }
+        let edge = |out: &mut String, from: Stage, to: Stage, attrs: &str| {
+            let _ = writeln!(
+                out,
+                "    {}0 -> {}0 [ltail=cluster_{}, lhead=cluster_{}{}];",
+                from.id(),
+                to.id(),
+                from.id(),
+                to.id(),
+                attrs
+            );
+        };
+        for (from, to) in self.flows() {
+            edge(&mut out, from, to, "");
+        }
+        for stage in self.monitored() {
+            edge(
+                &mut out,
+                stage,
+                Stage::Observability,
+                ", style=dashed, label=\"telemetry\"",
+            );
+        }
+        if let Some(stage) = self.governed() {
+            edge(
+                &mut out,
+                Stage::Governance,
+                stage,
+                ", style=dashed, dir=none, label=\"governs\"",
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagram() -> Diagram {
+        let scenario = Scenario {
+            sources: vec!["oltp".to_string(), "logs".to_string()],
+            ..Scenario::default()
+        };
+        Diagram::build(
+            &scenario,
+            &[
+                "Lakehouse",
+                "Monitoring Stack (Prometheus/Grafana)",
+                "Change Data Capture (Debezium)",
+                "Kafka/Flink",
+                "Access Control (Apache Ranger/Lake Formation)",
+            ],
+        )
+    }
+
+    #[test]
+    fn test_stages_in_flow_order() {
+        let stages: Vec<Stage> = diagram().stages.iter().map(|(s, _)| *s).collect();
+        assert_eq!(
+            stages,
+            vec![
+                Stage::Sources,
+                Stage::Ingestion,
+                Stage::Storage,
+                Stage::Processing,
+                Stage::Observability,
+                Stage::Governance,
+            ]
+        );
+        assert_eq!(
+            diagram().stages[0].1,
+            vec!["OLTP databases".to_string(), "Application logs".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_mermaid_and_dot_edges() {
+        let mermaid = diagram().mermaid();
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("        storage0[\"Lakehouse\"]\n"));
+        assert!(mermaid.contains("    sources --> ingestion\n"));
+        assert!(mermaid.contains("    storage --> processing\n"));
+        assert!(mermaid.contains("    processing -. telemetry .-> observability\n"));
+        assert!(mermaid.contains("    governance -. governs .- storage\n"));
+        assert!(!mermaid.contains("serving"));
+
+        let dot = diagram().graphviz();
+        assert!(dot.contains("subgraph cluster_ingestion {"));
+        assert!(dot
+            .contains("ingestion0 -> storage0 [ltail=cluster_ingestion, lhead=cluster_storage];"));
+
+        // Without ingestion the sources feed storage directly.
+        let direct = Diagram::build(&Scenario::default(), &["Data Warehouse"]);
+        assert!(direct.mermaid().contains("    sources --> storage\n"));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::Format;
+
+/// Update/delete needs the format rules know, from append-only to CDC-style upserts.
+pub const UPDATES: &[&str] = &["none", "occasional", "frequent"];
+
+/// Storage recommendations that keep data as open files on object storage or HDFS.
+const OPEN_FORMAT_STORAGE: &[&str] = &[
+    "Lakehouse",
+    "Data Lake (S3/ADLS)",
+    "Data Mesh",
+    "Hybrid Cloud",
+    "Hadoop/Spark",
+    "Managed Spark (Databricks/EMR)",
+];
+
+const WAREHOUSES: &[&str] = &["Data Warehouse", "Cloud DW (BigQuery/Snowflake)"];
+
+/// Recommendations that move data as serialized events.
+const EVENT_STREAMS: &[&str] = &[
+    "Kafka/Flink",
+    "Managed Kafka (Confluent Cloud/MSK)",
+    "Kafka Connect",
+    "Change Data Capture (Debezium)",
+];
+
+pub fn is_open_format_storage(name: &str) -> bool {
+    OPEN_FORMAT_STORAGE.contains(&name)
+}
+
+pub fn is_warehouse(name: &str) -> bool {
+    WAREHOUSES.contains(&name)
+}
+
+pub fn is_event_stream(name: &str) -> bool {
+    EVENT_STREAMS.contains(&name)
+}
+
+pub fn print(formats: &[Format]) {
+    if formats.is_empty() {
+        return;
+    }
+    println!("\n=== Data Formats ===");
+    let mut storage = "";
+    for Format(format, s, reason) in formats {
+        if *s != storage {
+            println!("{}:", s);
+            storage = s;
+        }
+        println!("- {}: {}", format, reason);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_kinds() {
+        assert!(is_open_format_storage("Lakehouse"));
+        assert!(!is_open_format_storage("Data Warehouse"));
+        assert!(is_warehouse("Data Warehouse"));
+        assert!(is_event_stream("Kafka/Flink"));
+    }
+}
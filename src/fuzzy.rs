@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+
+use crate::ahp::CRITERIA;
+use crate::catalog::ArchProfile;
+use crate::ranking::RankingStrategy;
+use crate::scenario::{Scenario, Weights};
+
+/// Degree (0..1) to which an input belongs to each value of a dimension,
+/// e.g. {"medium": 0.8, "large": 0.2}.
+pub type Membership = BTreeMap<String, f64>;
+
+/// Dimension names as used in `Scenario::memberships`, in `Weights` order.
+pub const DIMENSIONS: [&str; 5] = ["sla", "budget", "volume", "workload", "observability"];
+
+// Degree of a rule antecedent written as "any", matching the half score
+// "any" gets in the other modes.
+const ANY_DEGREE: f64 = 0.5;
+
+/// How the degrees of a rule's conditions are combined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TNorm {
+    Min,
+    Product,
+}
+
+impl TNorm {
+    pub fn parse(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "min" => Some(TNorm::Min),
+            "product" | "prod" => Some(TNorm::Product),
+            _ => None,
+        }
+    }
+
+    fn apply(&self, degrees: impl Iterator<Item = f64>) -> f64 {
+        match self {
+            TNorm::Min => degrees.fold(1.0, f64::min),
+            TNorm::Product => degrees.product(),
+        }
+    }
+}
+
+pub fn crisp(value: &str) -> Membership {
+    Membership::from([(value.to_string(), 1.0)])
+}
+
+/// Parses a typed membership vector such as "medium:0.6, large:0.4".
+pub fn parse_membership(input: &str) -> Option<Membership> {
+    let mut result = Membership::new();
+    for part in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (value, degree) = part.split_once(':')?;
+        let degree = degree.trim().parse::<f64>().ok()?;
+        if !(0.0..=1.0).contains(&degree) {
+            return None;
+        }
+        result.insert(value.trim().to_lowercase(), degree);
+    }
+    (!result.is_empty()).then_some(result)
+}
+
+/// Linear ramp from 0 at `from` to 1 at `to` (falling when `from > to`).
+fn ramp(x: f64, from: f64, to: f64) -> f64 {
+    ((x - from) / (to - from)).clamp(0.0, 1.0)
+}
+
+/// Volume memberships on a log scale: 1 TB and 100 TB, the crisp category
+/// boundaries, are where neighbouring sets overlap.
+pub fn volume_membership(tb: f64) -> Membership {
+    let x = tb.max(1e-3).log10();
+    let small = ramp(x, 0.5, -0.5);
+    let large = ramp(x, 1.0, 2.3);
+    let medium = ramp(x, -0.5, 0.5).min(ramp(x, 2.3, 1.0));
+    filtered([("small", small), ("medium", medium), ("large", large)])
+}
+
+/// Budget memberships around the price table's high-budget threshold.
+pub fn budget_membership(monthly: f64, threshold: f64) -> Membership {
+    let high = ramp(monthly, 0.5 * threshold, 1.5 * threshold);
+    filtered([("low", 1.0 - high), ("high", high)])
+}
+
+/// SLA memberships from a latency target: 1 s or faster is a high SLA,
+/// an hour or slower a low one.
+pub fn sla_membership(latency_ms: f64) -> Membership {
+    let high = ramp(latency_ms.max(1.0).log10(), 6.556, 3.0);
+    filtered([("low", 1.0 - high), ("high", high)])
+}
+
+fn filtered<const N: usize>(values: [(&str, f64); N]) -> Membership {
+    values
+        .into_iter()
+        .filter(|(_, d)| *d > 0.0)
+        .map(|(v, d)| (v.to_string(), (d * 100.0).round() / 100.0))
+        .collect()
+}
+
+/// Memberships per dimension in `Weights` order: typed vectors first, then
+/// vectors derived from numeric inputs, else the crisp answer.
+pub fn memberships(scenario: &Scenario, budget_threshold: f64) -> [Membership; 5] {
+    let derived = [
+        scenario.latency_ms.map(sla_membership),
+        scenario
+            .budget_monthly
+            .map(|b| budget_membership(b, budget_threshold)),
+        scenario.volume_tb.map(volume_membership),
+        None,
+        None,
+    ];
+    let crisp_values = [
+        &scenario.sla,
+        &scenario.budget,
+        &scenario.volume,
+        &scenario.workload,
+        &scenario.observability,
+    ];
+    let mut result: [Membership; 5] = Default::default();
+    for (i, membership) in result.iter_mut().enumerate() {
+        *membership = scenario
+            .memberships
+            .get(DIMENSIONS[i])
+            .cloned()
+            .or_else(|| derived[i].clone())
+            .unwrap_or_else(|| crisp(crisp_values[i]));
+    }
+    result
+}
+
+/// Degree to which a profile's rule fires. Each condition's degree is raised
+/// to its relative weight, so unimportant criteria matter less and weight 0
+/// drops a criterion.
+pub fn firing_degree(
+    profile: &ArchProfile,
+    memberships: &[Membership; 5],
+    weights: &Weights,
+    tnorm: TNorm,
+) -> f64 {
+    let values = [
+        profile.sla,
+        profile.budget,
+        profile.volume,
+        profile.workload,
+        profile.observability,
+    ];
+    let max_weight = weights.as_array().into_iter().max().unwrap_or(0).max(1) as f64;
+    let degrees =
+        values
+            .iter()
+            .zip(memberships)
+            .zip(weights.as_array())
+            .map(|((value, membership), w)| {
+                let degree = if *value == "any" {
+                    ANY_DEGREE
+                } else {
+                    membership.get(*value).copied().unwrap_or(0.0)
+                };
+                degree.powf(w.max(0) as f64 / max_weight)
+            });
+    tnorm.apply(degrees)
+}
+
+/// Fuzzy inference over the catalog: every profile is a rule, and its score
+/// is defuzzified as the firing degree times the best additive score.
+pub struct Fuzzy {
+    pub tnorm: TNorm,
+    pub budget_threshold: f64,
+}
+
+impl RankingStrategy for Fuzzy {
+    fn name(&self) -> &'static str {
+        match self.tnorm {
+            TNorm::Min => "fuzzy (min t-norm)",
+            TNorm::Product => "fuzzy (product t-norm)",
+        }
+    }
+
+    fn scores(&self, profiles: &[ArchProfile], scenario: &Scenario, weights: &Weights) -> Vec<i32> {
+        let memberships = memberships(scenario, self.budget_threshold);
+        let max_points = 10 * weights.sum();
+        profiles
+            .iter()
+            .map(|p| {
+                let degree = firing_degree(p, &memberships, weights, self.tnorm);
+                (degree * (p.base + max_points) as f64).round() as i32
+            })
+            .collect()
+    }
+}
+
+pub fn print_memberships(memberships: &[Membership; 5]) {
+    println!("\n=== Fuzzy Memberships ===");
+    for (name, membership) in CRITERIA.iter().zip(memberships) {
+        let values: Vec<String> = membership
+            .iter()
+            .map(|(v, d)| format!("{} {}", v, d))
+            .collect();
+        println!("- {}: {}", name, values.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derived_memberships() {
+        let twenty = volume_membership(20.0);
+        assert_eq!(twenty["medium"], 0.77);
+        assert_eq!(twenty["large"], 0.23);
+        assert_eq!(volume_membership(500.0), crisp("large"));
+        let budget = budget_membership(15_000.0, 20_000.0);
+        assert_eq!(budget["low"], 0.75);
+        assert_eq!(budget["high"], 0.25);
+        assert_eq!(sla_membership(100.0), crisp("high"));
+    }
+
+    #[test]
+    fn test_typed_membership_overrides_crisp() {
+        assert_eq!(parse_membership("medium:2"), None);
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "mixed".to_string(),
+            memberships: BTreeMap::from([(
+                "workload".to_string(),
+                parse_membership("mixed:0.7, streaming:0.3").unwrap(),
+            )]),
+            ..Scenario::default()
+        };
+        let m = memberships(&scenario, 20_000.0);
+        assert_eq!(m[2], crisp("large"));
+        assert_eq!(m[3]["streaming"], 0.3);
+    }
+
+    #[test]
+    fn test_t_norms() {
+        let profile = ArchProfile {
+            name: "Test",
+            volume: "large",
+            workload: "any",
+            sla: "high",
+            budget: "high",
+            observability: "any",
+            base: 80,
+        };
+        let mut m: [Membership; 5] = Default::default();
+        m[0] = crisp("high");
+        m[1] = budget_membership(15_000.0, 20_000.0);
+        m[2] = volume_membership(20.0);
+        let equal = Weights::from_array([1, 1, 1, 1, 1]);
+        let min = firing_degree(&profile, &m, &equal, TNorm::Min);
+        assert!((min - 0.23).abs() < 1e-9);
+        let product = firing_degree(&profile, &m, &equal, TNorm::Product);
+        assert!((product - 0.25 * 0.23 * 0.25).abs() < 1e-9);
+        // With weight 0 the volume condition no longer matters.
+        let no_volume = Weights::from_array([1, 1, 0, 1, 1]);
+        assert_eq!(firing_degree(&profile, &m, &no_volume, TNorm::Min), 0.25);
+    }
+}
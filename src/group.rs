@@ -0,0 +1,194 @@
+use crate::scenario::{Stakeholder, Weights};
+
+/// How stakeholder weight sets are combined into one consensus ranking.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Aggregation {
+    Mean,
+    Median,
+    GeometricMean,
+    MinimaxRegret,
+}
+
+impl Aggregation {
+    pub fn parse(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "mean" => Some(Aggregation::Mean),
+            "median" => Some(Aggregation::Median),
+            "geomean" | "geometric" => Some(Aggregation::GeometricMean),
+            "minimax" | "regret" => Some(Aggregation::MinimaxRegret),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Aggregation::Mean => "mean",
+            Aggregation::Median => "median",
+            Aggregation::GeometricMean => "geometric mean",
+            Aggregation::MinimaxRegret => "minimax regret",
+        }
+    }
+}
+
+fn combine(mut values: Vec<f64>, method: Aggregation) -> f64 {
+    let n = values.len() as f64;
+    match method {
+        Aggregation::Median => {
+            values.sort_by(|a, b| a.total_cmp(b));
+            let mid = values.len() / 2;
+            if values.len().is_multiple_of(2) {
+                (values[mid - 1] + values[mid]) / 2.0
+            } else {
+                values[mid]
+            }
+        }
+        Aggregation::GeometricMean => values.iter().product::<f64>().powf(1.0 / n),
+        // Minimax regret aggregates rankings, not weights; its weight set
+        // (used for adjustments and explanations) is the mean.
+        Aggregation::Mean | Aggregation::MinimaxRegret => values.iter().sum::<f64>() / n,
+    }
+}
+
+/// Consensus weight set, criterion by criterion.
+pub fn aggregate_weights(stakeholders: &[Stakeholder], method: Aggregation) -> Weights {
+    let mut result = [0; 5];
+    for (i, value) in result.iter_mut().enumerate() {
+        let values = stakeholders
+            .iter()
+            .map(|s| s.weights.as_array()[i].max(0) as f64)
+            .collect();
+        *value = combine(values, method).round() as i32;
+    }
+    Weights::from_array(result)
+}
+
+/// Ranks architectures by the largest score loss any stakeholder suffers
+/// compared to their own favourite, in percent of that favourite's score so
+/// that larger weight sets do not dominate. Scores are the negated maximum
+/// regret, so the best compromise has the highest score (0 if nobody loses).
+pub fn minimax_regret(rankings: &[Vec<(&'static str, i32)>]) -> Vec<(&'static str, i32)> {
+    let Some(first) = rankings.first() else {
+        return vec![];
+    };
+    let mut result: Vec<_> = first
+        .iter()
+        .map(|(name, _)| {
+            let regret = rankings
+                .iter()
+                .map(|ranking| {
+                    let best = ranking.first().map(|(_, s)| *s).unwrap_or(0);
+                    let own = ranking
+                        .iter()
+                        .find(|(n, _)| n == name)
+                        .map(|(_, s)| *s)
+                        .unwrap_or(0);
+                    (best - own) * 100 / best.max(1)
+                })
+                .max()
+                .unwrap_or(0);
+            (*name, -regret)
+        })
+        .collect();
+    result.sort_by_key(|(name, score)| (std::cmp::Reverse(*score), *name));
+    result
+}
+
+fn position(ranking: &[(&str, i32)], name: &str) -> Option<usize> {
+    ranking.iter().position(|(n, _)| *n == name).map(|i| i + 1)
+}
+
+/// Prints the consensus top entries next to each stakeholder's rank for
+/// them, and each stakeholder's own favourite where it differs.
+pub fn print_rankings(
+    stakeholders: &[Stakeholder],
+    rankings: &[Vec<(&'static str, i32)>],
+    consensus: &[(&'static str, i32)],
+    method: Aggregation,
+    top: usize,
+) {
+    println!("\n=== Group Decision ({}) ===", method.label());
+    if method != Aggregation::MinimaxRegret {
+        let w = aggregate_weights(stakeholders, method);
+        println!(
+            "consensus weights: sla {}, budget {}, volume {}, workload {}, observability {}",
+            w.sla, w.budget, w.volume, w.workload, w.observability
+        );
+    }
+    let names: Vec<&str> = stakeholders.iter().map(|s| s.name.as_str()).collect();
+    println!("rank | consensus | {}", names.join(" | "));
+    for (i, (name, score)) in consensus.iter().take(top).enumerate() {
+        let ranks: Vec<String> = rankings
+            .iter()
+            .map(|r| match position(r, name) {
+                Some(p) => format!("#{}", p),
+                None => "-".to_string(),
+            })
+            .collect();
+        println!(
+            "{:>4} | {} ({}) | {}",
+            i + 1,
+            name,
+            score,
+            ranks.join(" | ")
+        );
+    }
+    let Some((winner, _)) = consensus.first() else {
+        return;
+    };
+    for (stakeholder, ranking) in stakeholders.iter().zip(rankings) {
+        if let Some((favourite, _)) = ranking.first() {
+            if favourite != winner {
+                println!(
+                    "! {} prefers {} (consensus pick is #{} for them)",
+                    stakeholder.name,
+                    favourite,
+                    position(ranking, winner).unwrap_or(0)
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stakeholder(name: &str, weights: [i32; 5]) -> Stakeholder {
+        Stakeholder {
+            name: name.to_string(),
+            weights: Weights::from_array(weights),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_weights() {
+        let group = vec![
+            stakeholder("sla-owner", [9, 1, 2, 1, 4]),
+            stakeholder("finance", [1, 9, 2, 1, 1]),
+            stakeholder("platform", [4, 4, 2, 4, 9]),
+        ];
+        let mean = aggregate_weights(&group, Aggregation::Mean);
+        assert_eq!(mean.as_array(), [5, 5, 2, 2, 5]);
+        let median = aggregate_weights(&group, Aggregation::Median);
+        assert_eq!(median.as_array(), [4, 4, 2, 1, 4]);
+        let geo = aggregate_weights(&group, Aggregation::GeometricMean);
+        assert_eq!(geo.as_array(), [3, 3, 2, 2, 3]);
+        assert_eq!(
+            Aggregation::parse("geomean"),
+            Some(Aggregation::GeometricMean)
+        );
+        assert_eq!(Aggregation::parse("vote"), None);
+    }
+
+    #[test]
+    fn test_minimax_regret_prefers_compromise() {
+        let rankings = vec![
+            vec![("A", 100), ("C", 90), ("B", 50)],
+            vec![("B", 100), ("C", 85), ("A", 40)],
+        ];
+        let consensus = minimax_regret(&rankings);
+        assert_eq!(consensus[0], ("C", -15));
+        assert_eq!(consensus[1], ("B", -50));
+        assert_eq!(consensus[2], ("A", -60));
+    }
+}
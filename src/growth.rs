@@ -0,0 +1,246 @@
+use crate::ranking::RankingStrategy;
+use crate::scenario::{volume_category, Scenario, Weights};
+use crate::{evaluate_multi_with, evaluate_strict, Explanation, Recommendation};
+
+/// Evaluation results for the scenario projected to a given year.
+pub struct Milestone {
+    pub year: u32,
+    pub volume_tb: f64,
+    pub volume: &'static str,
+    pub strict_top: Option<&'static str>,
+    pub multi_top: &'static str,
+    /// Strict-mode explanations keyed by architecture name.
+    pub explanations: Vec<(&'static str, &'static str)>,
+}
+
+/// A change of the recommended architecture between two consecutive milestones.
+pub struct Transition {
+    pub mode: &'static str,
+    pub year: u32,
+    pub from: &'static str,
+    pub to: &'static str,
+    pub reason: String,
+}
+
+/// Longest projection horizon, in years; `project` clamps to `1..=MAX_HORIZON_YEARS`.
+pub const MAX_HORIZON_YEARS: u32 = 10;
+
+/// Checks the yearly growth rate: it must be finite and above -100%.
+pub fn validate_growth(growth_pct: f64) -> Result<f64, String> {
+    if !growth_pct.is_finite() {
+        return Err(format!(
+            "Growth rate must be a finite number (got {})",
+            growth_pct
+        ));
+    }
+    if growth_pct <= -100.0 {
+        return Err(format!(
+            "Growth rate must be above -100% (got {}%): the volume would vanish",
+            growth_pct
+        ));
+    }
+    Ok(growth_pct)
+}
+
+/// Converts the horizon prompt to whole years in `1..=MAX_HORIZON_YEARS`.
+pub fn validate_horizon(years: f64) -> Result<u32, String> {
+    if !years.is_finite() {
+        return Err(format!(
+            "Projection horizon must be a finite number (got {})",
+            years
+        ));
+    }
+    Ok(years.clamp(1.0, MAX_HORIZON_YEARS as f64) as u32)
+}
+
+pub fn project_volume(volume_tb: f64, growth_pct: f64, years: u32) -> f64 {
+    volume_tb * (1.0 + growth_pct / 100.0).powi(years as i32)
+}
+
+/// Evaluates strict mode and multi mode (with the selected ranking) at every
+/// year from now up to `horizon_years`, clamped to `1..=MAX_HORIZON_YEARS`.
+pub fn project(
+    scenario: &Scenario,
+    weights: &Weights,
+    strategy: &dyn RankingStrategy,
+    growth_pct: f64,
+    horizon_years: u32,
+) -> Vec<Milestone> {
+    let current_tb = scenario.effective_volume_tb();
+    (0..=horizon_years.clamp(1, MAX_HORIZON_YEARS))
+        .map(|year| {
+            let volume_tb = project_volume(current_tb, growth_pct, year);
+            let volume = volume_category(volume_tb);
+            let projected = Scenario {
+                volume: volume.to_string(),
+                volume_tb: Some(volume_tb),
+                ..scenario.clone()
+            };
+            let (recs, expls) = evaluate_strict(&projected, weights);
+            let multi = evaluate_multi_with(&projected, weights, strategy);
+            Milestone {
+                year,
+                volume_tb,
+                volume,
+                strict_top: recs.first().map(|Recommendation(r, _)| *r),
+                multi_top: multi[0].0,
+                explanations: expls
+                    .into_iter()
+                    .map(|Explanation(name, text)| (name, text))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+pub fn transitions(milestones: &[Milestone]) -> Vec<Transition> {
+    let mut result = Vec::new();
+    for pair in milestones.windows(2) {
+        let (prev, next) = (&pair[0], &pair[1]);
+        let volume_change = format!(
+            "volume grows from {:.1} TB ({}) to {:.1} TB ({})",
+            prev.volume_tb, prev.volume, next.volume_tb, next.volume
+        );
+        let explain = |name: &str| {
+            next.explanations
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, text)| format!("{}; {}", volume_change, text))
+                .unwrap_or_else(|| volume_change.clone())
+        };
+
+        match (prev.strict_top, next.strict_top) {
+            (Some(from), Some(to)) if from != to => result.push(Transition {
+                mode: "strict",
+                year: next.year,
+                from,
+                to,
+                reason: explain(to),
+            }),
+            (Some(from), None) => result.push(Transition {
+                mode: "strict",
+                year: next.year,
+                from,
+                to: "no strict match",
+                reason: volume_change.clone(),
+            }),
+            (None, Some(to)) => result.push(Transition {
+                mode: "strict",
+                year: next.year,
+                from: "no strict match",
+                to,
+                reason: explain(to),
+            }),
+            _ => {}
+        }
+
+        if prev.multi_top != next.multi_top {
+            result.push(Transition {
+                mode: "multi",
+                year: next.year,
+                from: prev.multi_top,
+                to: next.multi_top,
+                reason: explain(next.multi_top),
+            });
+        }
+    }
+    result
+}
+
+pub fn print_roadmap(milestones: &[Milestone], growth_pct: f64) {
+    println!(
+        "\n=== Evolution Roadmap ({}% yearly growth) ===",
+        growth_pct
+    );
+    for m in milestones {
+        println!(
+            "Year {}: {:.1} TB ({}) | strict: {} | multi: {}",
+            m.year,
+            m.volume_tb,
+            m.volume,
+            m.strict_top.unwrap_or("-"),
+            m.multi_top
+        );
+    }
+
+    let changes = transitions(milestones);
+    println!("\n=== Transitions ===");
+    if changes.is_empty() {
+        println!("- No architecture change expected within the horizon.");
+    }
+    for t in changes {
+        println!(
+            "- [{}] {} -> {} in year {}: {}",
+            t.mode, t.from, t.to, t.year, t.reason
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ranking::{Additive, Topsis};
+
+    fn scenario(volume_tb: f64) -> Scenario {
+        Scenario {
+            volume: volume_category(volume_tb).to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "low".to_string(),
+            volume_tb: Some(volume_tb),
+            ..Scenario::default()
+        }
+    }
+
+    #[test]
+    fn test_project_volume_compounds() {
+        let tb = project_volume(50.0, 100.0, 2);
+        assert_eq!(tb, 200.0);
+    }
+
+    #[test]
+    fn test_project_clamps_horizon() {
+        let weights = Weights::default();
+        let longest = project(&scenario(60.0), &weights, &Additive, 10.0, u32::MAX);
+        assert_eq!(longest.len(), MAX_HORIZON_YEARS as usize + 1);
+        let shortest = project(&scenario(60.0), &weights, &Additive, 10.0, 0);
+        assert_eq!(shortest.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_growth_inputs() {
+        assert_eq!(validate_growth(30.0), Ok(30.0));
+        assert!(validate_growth(-100.0).is_err());
+        assert!(validate_growth(f64::NAN).is_err());
+        assert!(validate_growth(f64::INFINITY).is_err());
+
+        assert_eq!(validate_horizon(3.0), Ok(3));
+        assert_eq!(validate_horizon(0.0), Ok(1));
+        assert_eq!(validate_horizon(1e12), Ok(MAX_HORIZON_YEARS));
+        assert!(validate_horizon(f64::INFINITY).is_err());
+        assert!(validate_horizon(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_medium_grows_into_lakehouse() {
+        let milestones = project(&scenario(60.0), &Weights::default(), &Additive, 50.0, 3);
+        assert_eq!(milestones.len(), 4);
+        assert_eq!(milestones[0].volume, "medium");
+        assert_eq!(milestones[2].volume, "large");
+
+        let changes = transitions(&milestones);
+        let strict = changes.iter().find(|t| t.mode == "strict").unwrap();
+        assert_eq!(strict.year, 2);
+        assert_eq!(strict.to, "Lakehouse");
+        assert!(strict.reason.contains("Lakehouse chosen"));
+    }
+
+    #[test]
+    fn test_multi_top_follows_ranking_strategy() {
+        let weights = Weights::default();
+        let milestones = project(&scenario(60.0), &weights, &Topsis, 50.0, 1);
+        let topsis = crate::evaluate_multi_with(&scenario(60.0), &weights, &Topsis);
+        assert_eq!(milestones[0].multi_top, topsis[0].0);
+    }
+}
@@ -0,0 +1,279 @@
+use std::fmt::Write;
+
+use crate::ahp::CRITERIA;
+use crate::catalog::profiles_for;
+use crate::cost::format_money;
+use crate::ranking::fits;
+use crate::repl::inputs;
+use crate::report::Report;
+use crate::scenario::{Scenario, Weights};
+
+// Inline styles only, so the file opens offline and survives ticket attachments.
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+h1 { font-size: 1.6em; } h2 { font-size: 1.2em; margin-top: 2em; }
+table { border-collapse: collapse; } th, td { padding: 0.25em 0.75em; text-align: left; }
+th { border-bottom: 1px solid #999; } td.num { text-align: right; }
+.bar-row { display: flex; align-items: center; margin: 0.2em 0; }
+.bar-name { width: 22em; font-size: 0.9em; }
+.bar { background: #4a7bd0; color: #fff; padding: 0.1em 0.4em; font-size: 0.8em; white-space: nowrap; }
+.bar.top { background: #2e9d5b; }
+.cost { margin-left: 0.6em; font-size: 0.8em; color: #666; }
+.warning { border-left: 4px solid #c93; padding: 0.2em 0.8em; margin: 0.5em 0; }
+.warning.high { border-color: #c33; } .warning.low { border-color: #999; }
+";
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Points each criterion adds to an architecture's additive score, in
+/// `Weights` order, or None for architectures without a catalog profile.
+pub fn breakdown(scenario: &Scenario, weights: &Weights, name: &str) -> Option<(i32, [i32; 5])> {
+    let profile = profiles_for(scenario)
+        .into_iter()
+        .find(|p| p.name == name)?;
+    let mut points = [0; 5];
+    for ((p, fit), w) in points
+        .iter_mut()
+        .zip(fits(&profile, scenario))
+        .zip(weights.as_array())
+    {
+        *p = (fit * 10.0 * w as f64).round() as i32;
+    }
+    Some((profile.base, points))
+}
+
+/// Single-file HTML report of one run. `breakdown` adds the profile points
+/// per dimension, which only add up to the scores under additive ranking.
+pub fn render(
+    report: &Report,
+    scenario: &Scenario,
+    weights: &Weights,
+    strict_explanations: &[String],
+    method: &str,
+    breakdown: bool,
+) -> String {
+    let mut html = String::new();
+    let _ = writeln!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Architecture Recommendation</title>\n<style>{}</style>\n</head>\n<body>",
+        STYLE
+    );
+    let _ = writeln!(html, "<h1>Architecture Recommendation</h1>");
+    let _ = writeln!(html, "<p>Ranking: {}</p>", escape(method));
+
+    let _ = writeln!(html, "<h2>Inputs</h2>\n<table>");
+    for (input, value) in inputs(&(scenario.clone(), *weights)) {
+        if value != "-" && !input.starts_with("weight ") {
+            let _ = writeln!(
+                html,
+                "<tr><th>{}</th><td>{}</td></tr>",
+                escape(&input),
+                escape(&value)
+            );
+        }
+    }
+    let _ = writeln!(html, "</table>\n<h2>Weights</h2>\n<table>");
+    for (criterion, weight) in CRITERIA.iter().zip(weights.as_array()) {
+        let _ = writeln!(
+            html,
+            "<tr><th>{}</th><td class=\"num\">{}</td></tr>",
+            criterion, weight
+        );
+    }
+    let _ = writeln!(html, "</table>");
+
+    let _ = writeln!(html, "<h2>Recommendations</h2>");
+    if report.entries.is_empty() {
+        let _ = writeln!(html, "<p>No architecture matched the inputs.</p>");
+    }
+    let max = report
+        .entries
+        .iter()
+        .map(|e| e.score)
+        .max()
+        .unwrap_or(1)
+        .max(1);
+    for (i, entry) in report.entries.iter().enumerate() {
+        // The best score takes half the row, leaving room for name and cost.
+        let width = (entry.score.max(0) as f64 / max as f64 * 50.0).round();
+        let cost = entry.cost.map_or(String::new(), |c| {
+            format!(
+                "<span class=\"cost\">~{}/month</span>",
+                format_money(c.monthly, &report.currency)
+            )
+        });
+        let _ = writeln!(
+            html,
+            "<div class=\"bar-row\"><span class=\"bar-name\">{}</span>\
+             <span class=\"bar{}\" style=\"width: {}%\">{}</span>{}</div>",
+            escape(&entry.name),
+            if i == 0 { " top" } else { "" },
+            width,
+            entry.score,
+            cost
+        );
+    }
+
+    if !strict_explanations.is_empty() {
+        let _ = writeln!(html, "<h2>Rule Explanations</h2>\n<ul>");
+        for e in strict_explanations {
+            let _ = writeln!(html, "<li>{}</li>", escape(e));
+        }
+        let _ = writeln!(html, "</ul>");
+    }
+
+    if breakdown {
+        score_breakdown(&mut html, report, scenario, weights);
+    }
+
+    if !report.warnings.is_empty() {
+        let _ = writeln!(html, "<h2>Warnings</h2>");
+        for w in &report.warnings {
+            let _ = writeln!(
+                html,
+                "<div class=\"warning {}\"><strong>[{}]</strong> {}<br>Mitigation: {}</div>",
+                escape(&w.severity),
+                escape(&w.severity),
+                escape(&w.message),
+                escape(&w.mitigation)
+            );
+        }
+    }
+    let _ = writeln!(html, "</body>\n</html>");
+    html
+}
+
+/// Table of base, per-criterion points and adjustments of each entry.
+fn score_breakdown(html: &mut String, report: &Report, scenario: &Scenario, weights: &Weights) {
+    let _ = writeln!(
+        html,
+        "<h2>Score Breakdown</h2>\n<p>Additive points per dimension: fit (1 match, 0.5 any, 0 mismatch) \
+         &times; 10 &times; weight.</p>\n<table>\n<tr><th>Architecture</th><th>Base</th>"
+    );
+    for criterion in CRITERIA {
+        let _ = write!(html, "<th>{}</th>", criterion);
+    }
+    let _ = writeln!(html, "<th>Adjustments</th></tr>");
+    for entry in &report.entries {
+        let Some((base, points)) = breakdown(scenario, weights, &entry.name) else {
+            continue;
+        };
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td class=\"num\">{}</td>",
+            escape(&entry.name),
+            base
+        );
+        for p in points {
+            let _ = write!(html, "<td class=\"num\">{}</td>", p);
+        }
+        let adjustments: Vec<String> = entry
+            .adjustments
+            .iter()
+            .map(|a| format!("{:+} {}", a.points, escape(&a.reason)))
+            .collect();
+        let _ = writeln!(html, "<td>{}</td></tr>", adjustments.join("<br>"));
+    }
+    let _ = writeln!(html, "</table>");
+}
+
+pub fn write(
+    path: &str,
+    report: &Report,
+    scenario: &Scenario,
+    weights: &Weights,
+    strict_explanations: &[String],
+    method: &str,
+    breakdown: bool,
+) -> Result<(), String> {
+    let html = render(
+        report,
+        scenario,
+        weights,
+        strict_explanations,
+        method,
+        breakdown,
+    );
+    std::fs::write(path, html).map_err(|e| format!("{}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cost::PriceTable;
+    use crate::report::RiskWarning;
+
+    fn scenario() -> Scenario {
+        Scenario {
+            volume: "large".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "low".to_string(),
+            observability: "high".to_string(),
+            ..Scenario::default()
+        }
+    }
+
+    #[test]
+    fn test_breakdown_adds_up_to_additive_score() {
+        let weights = Weights::default();
+        let (base, points) = breakdown(&scenario(), &weights, "Lakehouse").unwrap();
+        // Lakehouse wants a high budget: every criterion but budget matches.
+        assert_eq!(points, [50, 0, 20, 10, 40]);
+        let score = crate::similarity_score(
+            scenario().as_tuple(),
+            weights.as_tuple(),
+            &profiles_for(&scenario())
+                .into_iter()
+                .find(|p| p.name == "Lakehouse")
+                .unwrap(),
+        );
+        assert_eq!(base + points.iter().sum::<i32>(), score);
+        assert_eq!(breakdown(&scenario(), &weights, "Unknown"), None);
+    }
+
+    #[test]
+    fn test_render_is_self_contained_and_escaped() {
+        let weights = Weights::default();
+        let report = Report::new(
+            &[("Lakehouse", 215), ("Data Mesh", 200)],
+            vec![],
+            &scenario(),
+            &weights,
+            &PriceTable::default(),
+        )
+        .with_warnings(vec![RiskWarning {
+            message: "Costs <grow> fast.".to_string(),
+            severity: "high".to_string(),
+            mitigation: "Tag & budget.".to_string(),
+        }]);
+        let html = render(
+            &report,
+            &scenario(),
+            &weights,
+            &["Lakehouse chosen for large mixed workloads.".to_string()],
+            "additive",
+            true,
+        );
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("src=") && !html.contains("href=") && !html.contains("http"));
+        assert!(html.contains("<span class=\"bar top\" style=\"width: 50%\">215</span>"));
+        assert!(html.contains("<li>Lakehouse chosen for large mixed workloads.</li>"));
+        assert!(html.contains("Costs &lt;grow&gt; fast."));
+        assert!(html.contains("Tag &amp; budget."));
+        assert!(html.contains("<tr><th>volume</th><td>large</td></tr>"));
+        assert!(html.contains("<h2>Score Breakdown</h2>"));
+
+        // Profile points do not add up to strict or TOPSIS scores.
+        for method in ["strict rules", "TOPSIS"] {
+            let html = render(&report, &scenario(), &weights, &[], method, false);
+            assert!(!html.contains("Score Breakdown"));
+        }
+    }
+}
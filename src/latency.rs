@@ -0,0 +1,181 @@
+use crate::adjustments::Adjustment;
+use crate::catalog::{AVAILABILITY, LATENCY_RANGES};
+use crate::scenario::{Scenario, Weights};
+
+/// Parses a latency target such as "100ms", "5s", "15m", "2h" or a plain
+/// number of milliseconds.
+pub fn parse_latency(input: &str) -> Option<f64> {
+    let s = input.trim().to_lowercase().replace(' ', "");
+    let (digits, factor) = if let Some(v) = s.strip_suffix("ms") {
+        (v, 1.0)
+    } else if let Some(v) = s.strip_suffix("min") {
+        (v, 60_000.0)
+    } else if let Some(v) = s.strip_suffix('s') {
+        (v, 1000.0)
+    } else if let Some(v) = s.strip_suffix('m') {
+        (v, 60_000.0)
+    } else if let Some(v) = s.strip_suffix('h') {
+        (v, 3_600_000.0)
+    } else {
+        (s.as_str(), 1.0)
+    };
+    digits
+        .parse::<f64>()
+        .ok()
+        .filter(|v| *v > 0.0)
+        .map(|v| v * factor)
+}
+
+/// Parses an availability target as a percentage ("99.95", "99.9%") or a
+/// number of nines ("3", "4 nines").
+pub fn parse_availability(input: &str) -> Option<f64> {
+    let s = input.trim().to_lowercase();
+    let s = s.trim_end_matches("nines").trim().trim_end_matches('%');
+    let value = s.parse::<f64>().ok().filter(|v| *v > 0.0)?;
+    if value < 10.0 {
+        let pct = 100.0 * (1.0 - 10f64.powf(-value));
+        Some((pct * 1e6).round() / 1e6)
+    } else if value < 100.0 {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+pub fn latency_class(ms: f64) -> &'static str {
+    if ms <= 100.0 {
+        "realtime"
+    } else if ms <= 10_000.0 {
+        "streaming"
+    } else if ms <= 900_000.0 {
+        "micro-batch"
+    } else {
+        "batch"
+    }
+}
+
+pub fn availability_class(pct: f64) -> &'static str {
+    if pct >= 99.99 {
+        "critical"
+    } else if pct >= 99.9 {
+        "high"
+    } else {
+        "standard"
+    }
+}
+
+pub fn format_duration(ms: f64) -> String {
+    if ms < 1000.0 {
+        format!("{} ms", ms)
+    } else if ms < 60_000.0 {
+        format!("{} s", ms / 1000.0)
+    } else if ms < 3_600_000.0 {
+        format!("{} min", ms / 60_000.0)
+    } else {
+        format!("{} h", ms / 3_600_000.0)
+    }
+}
+
+/// Score changes from the latency and availability targets. Latency splits
+/// out of the SLA criterion, so it is weighted with the SLA weight.
+pub fn adjust(scenario: &Scenario, weights: &Weights, name: &str) -> Vec<Adjustment> {
+    let mut result = Vec::new();
+
+    let range = LATENCY_RANGES.iter().find(|(n, _, _)| *n == name);
+    if let (Some(target), Some((_, min, max))) = (scenario.latency_ms, range) {
+        let span = format!("{}–{}", format_duration(*min), format_duration(*max));
+        if target < *min {
+            result.push(Adjustment::new(
+                -weights.sla * 10,
+                format!(
+                    "latency: {} target is faster than its {} range",
+                    format_duration(target),
+                    span
+                ),
+            ));
+        } else if target > *max {
+            result.push(Adjustment::new(
+                -weights.sla * 3,
+                format!(
+                    "latency: {} target does not need its {} range",
+                    format_duration(target),
+                    span
+                ),
+            ));
+        } else {
+            result.push(Adjustment::new(
+                weights.sla * 10,
+                format!(
+                    "latency: {} target fits its {} range",
+                    format_duration(target),
+                    span
+                ),
+            ));
+        }
+    }
+
+    let achievable = AVAILABILITY.iter().find(|(n, _)| *n == name);
+    if let (Some(target), Some((_, max))) = (scenario.availability, achievable) {
+        if target > *max {
+            result.push(Adjustment::new(
+                -weights.sla * 5,
+                format!(
+                    "availability: {}% target above its typical {}%",
+                    target, max
+                ),
+            ));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_latency_units() {
+        assert_eq!(parse_latency("100ms"), Some(100.0));
+        assert_eq!(parse_latency("5s"), Some(5000.0));
+        assert_eq!(parse_latency("15m"), Some(900_000.0));
+        assert_eq!(parse_latency("2h"), Some(7_200_000.0));
+        assert_eq!(parse_latency("250"), Some(250.0));
+        assert_eq!(parse_latency(""), None);
+    }
+
+    #[test]
+    fn test_parse_availability_nines() {
+        assert_eq!(parse_availability("99.95%"), Some(99.95));
+        let three = parse_availability("3 nines").unwrap();
+        assert_eq!(three, 99.9);
+        assert_eq!(availability_class(three), "high");
+        assert_eq!(parse_availability("100"), None);
+    }
+
+    #[test]
+    fn test_latency_classes() {
+        assert_eq!(latency_class(50.0), "realtime");
+        assert_eq!(latency_class(5000.0), "streaming");
+        assert_eq!(latency_class(300_000.0), "micro-batch");
+        assert_eq!(latency_class(3_600_000.0), "batch");
+    }
+
+    #[test]
+    fn test_adjust_penalizes_too_slow_architectures() {
+        let scenario = Scenario {
+            latency_ms: Some(50.0),
+            availability: Some(99.99),
+            ..Scenario::default()
+        };
+        let weights = Weights::default();
+        let hadoop = adjust(&scenario, &weights, "Hadoop/Spark");
+        assert_eq!(hadoop.iter().map(|a| a.points).sum::<i32>(), -75);
+        let kv = adjust(
+            &scenario,
+            &weights,
+            "Key-Value Store (Redis/Cassandra/DynamoDB)",
+        );
+        assert_eq!(kv.len(), 1);
+        assert_eq!(kv[0].points, 50);
+    }
+}
@@ -1,111 +1,530 @@
+mod adjustments;
+mod adr;
+mod ahp;
+mod catalog;
+mod compare;
+mod compliance;
+mod cost;
+mod diagram;
+mod formats;
+mod fuzzy;
+mod group;
+mod growth;
+mod html;
+mod latency;
+mod migration;
+mod ml;
+mod pareto;
+mod questionnaire;
+mod ranking;
+mod repl;
+mod report;
+mod scenario;
+mod security;
+mod serving;
+mod team;
+mod tui;
+
+use catalog::{profiles_for, ArchProfile};
+use cost::PriceTable;
 use crepe::crepe;
+use group::Aggregation;
+use ranking::RankingStrategy;
+use report::{Report, RiskWarning};
+use scenario::{
+    parse_budget, parse_list, parse_names, parse_volume, Scenario, ScenarioFile, Stakeholder,
+    Weights,
+};
+use std::collections::{BTreeMap, HashSet};
 use std::env;
 
+// Multi mode ranks the whole catalog, so only its head counts as chosen.
+const MULTI_TOP: usize = 5;
+
+// Flags followed by a file, directory or method name.
+const VALUE_FLAGS: &[&str] = &[
+    "--prices",
+    "--tnorm",
+    "--ranking",
+    "--scenario",
+    "--aggregate",
+    "--current",
+    "--json",
+    "--mermaid",
+    "--graphviz",
+    "--adr",
+    "--html",
+];
+
 crepe! {
     @input
     struct Volume(&'static str);
     @input
     struct Workload(&'static str);
     @input
+    #[allow(clippy::upper_case_acronyms)]
     struct SLA(&'static str);
     @input
     struct Budget(&'static str);
     @input
     struct Observability(&'static str);
+    @input
+    struct Compliance(&'static str);
+    @input
+    struct Residency(&'static str);
+    @input
+    struct LatencyClass(&'static str);
+    @input
+    struct AvailabilityClass(&'static str);
+    @input
+    struct TeamSize(&'static str);
+    @input
+    struct OpsMaturity(&'static str);
+    @input
+    struct MissingSkill(&'static str);
+    @input
+    struct AccessPattern(&'static str);
+    @input
+    struct Source(&'static str);
+    @input
+    struct MlNeed(&'static str);
+    @input
+    struct DataQuality(&'static str);
+    @input
+    struct SchemaVolatility(&'static str);
+    @input
+    struct Updates(&'static str);
+    @input
+    struct SecurityPosture(&'static str);
+    @input
+    struct Picked(&'static str);
 
     @output
     struct Recommendation(&'static str, i32);
     @output
-    struct Explanation(&'static str);
+    struct Explanation(&'static str, &'static str);
+    @output
+    struct Format(&'static str, &'static str, &'static str);
+    @output
+    struct SecurityControl(&'static str, &'static str, &'static str);
+    @output
+    struct Warning(&'static str, &'static str, &'static str);
 
     // === Strict rules for default mode ===
     Recommendation("Hadoop/Spark", 60) <- Volume("large"), Workload("batch"), SLA("low"), Budget("low");
-    Explanation("Hadoop/Spark chosen for large batch data, low SLA, limited budget.")
+    Explanation("Hadoop/Spark", "Hadoop/Spark chosen for large batch data, low SLA, limited budget.")
         <- Volume("large"), Workload("batch"), SLA("low"), Budget("low");
 
     Recommendation("Data Warehouse", 70) <- Volume("medium"), Workload("batch"), SLA("high");
-    Explanation("Data Warehouse chosen for medium batch workloads with high SLA.")
+    Explanation("Data Warehouse", "Data Warehouse chosen for medium batch workloads with high SLA.")
         <- Volume("medium"), Workload("batch"), SLA("high");
 
     Recommendation("Kafka/Flink", 90) <- Workload("streaming"), SLA("high");
-    Explanation("Kafka/Flink chosen for real-time streaming with strict SLA.")
+    Explanation("Kafka/Flink", "Kafka/Flink chosen for real-time streaming with strict SLA.")
         <- Workload("streaming"), SLA("high");
 
     Recommendation("Lakehouse", 95) <- Volume("large"), Workload("mixed"), SLA("high"), Budget("high");
-    Explanation("Lakehouse chosen for large mixed workloads, high SLA, sufficient budget.")
+    Explanation("Lakehouse", "Lakehouse chosen for large mixed workloads, high SLA, sufficient budget.")
         <- Volume("large"), Workload("mixed"), SLA("high"), Budget("high");
 
     Recommendation("Cloud DW (BigQuery/Snowflake)", 85) <- Volume("medium"), Workload("mixed"), SLA("high"), Budget("high");
-    Explanation("Cloud DW chosen for medium mixed workloads, high SLA, high budget.")
+    Explanation("Cloud DW (BigQuery/Snowflake)", "Cloud DW chosen for medium mixed workloads, high SLA, high budget.")
         <- Volume("medium"), Workload("mixed"), SLA("high"), Budget("high");
 
     Recommendation("Data Mesh", 90) <- Volume("large"), Workload("mixed"), SLA("high");
-    Explanation("Data Mesh chosen for large distributed domains, mixed workloads, decentralized ownership.")
+    Explanation("Data Mesh", "Data Mesh chosen for large distributed domains, mixed workloads, decentralized ownership.")
         <- Volume("large"), Workload("mixed"), SLA("high");
 
     Recommendation("ETL Pipelines", 50) <- Workload("batch"), SLA("low"), Budget("low");
-    Explanation("ETL Pipelines chosen for batch workloads, relaxed SLA, limited budget.")
+    Explanation("ETL Pipelines", "ETL Pipelines chosen for batch workloads, relaxed SLA, limited budget.")
         <- Workload("batch"), SLA("low"), Budget("low");
 
     Recommendation("ML Platform (Kubeflow/MLflow)", 88) <- Workload("mixed"), SLA("high"), Budget("high");
-    Explanation("ML Platform chosen for advanced analytics, ML, sufficient budget.")
+    Explanation("ML Platform (Kubeflow/MLflow)", "ML Platform chosen for advanced analytics, ML, sufficient budget.")
         <- Workload("mixed"), SLA("high"), Budget("high");
 
     Recommendation("Hybrid Cloud", 80) <- Volume("large"), Workload("mixed"), SLA("high");
-    Explanation("Hybrid Cloud chosen for large mixed workloads, strict SLA, flexibility across on-prem/cloud.")
+    Explanation("Hybrid Cloud", "Hybrid Cloud chosen for large mixed workloads, strict SLA, flexibility across on-prem/cloud.")
         <- Volume("large"), Workload("mixed"), SLA("high");
 
     Recommendation("Data Lake (S3/ADLS)", 75) <- Volume("large"), Workload("batch"), SLA("high"), Budget("high");
-    Explanation("Data Lake chosen for large-scale storage, batch workloads, high SLA, high budget.")
+    Explanation("Data Lake (S3/ADLS)", "Data Lake chosen for large-scale storage, batch workloads, high SLA, high budget.")
         <- Volume("large"), Workload("batch"), SLA("high"), Budget("high");
 
     Recommendation("Orchestration (Airflow/Prefect)", 65) <- Workload("batch"), SLA("high");
-    Explanation("Orchestration chosen for managing complex batch pipelines with SLA guarantees.")
+    Explanation("Orchestration (Airflow/Prefect)", "Orchestration chosen for managing complex batch pipelines with SLA guarantees.")
         <- Workload("batch"), SLA("high");
 
     Recommendation("Governance Layer (Collibra/Alation)", 70) <- Volume("large"), SLA("high");
-    Explanation("Governance chosen for large datasets with strict SLA and compliance needs.")
+    Explanation("Governance Layer (Collibra/Alation)", "Governance chosen for large datasets with strict SLA and compliance needs.")
         <- Volume("large"), SLA("high");
 
     // === Observability rules ===
     Recommendation("Monitoring Stack (Prometheus/Grafana)", 85) <- Observability("high");
-    Explanation("Monitoring stack chosen for deep metrics, dashboards, and SLA compliance.")
+    Explanation("Monitoring Stack (Prometheus/Grafana)", "Monitoring stack chosen for deep metrics, dashboards, and SLA compliance.")
         <- Observability("high");
 
     Recommendation("Distributed Tracing (OpenTelemetry/Jaeger)", 88) <- Observability("high"), Workload("streaming");
-    Explanation("Tracing chosen for end-to-end visibility across streaming pipelines.")
+    Explanation("Distributed Tracing (OpenTelemetry/Jaeger)", "Tracing chosen for end-to-end visibility across streaming pipelines.")
         <- Observability("high"), Workload("streaming");
 
     Recommendation("Data Lineage Tools (OpenLineage/Marquez)", 80) <- Observability("high"), Volume("large");
-    Explanation("Lineage tools chosen for compliance and auditability in large-scale pipelines.")
+    Explanation("Data Lineage Tools (OpenLineage/Marquez)", "Lineage tools chosen for compliance and auditability in large-scale pipelines.")
         <- Observability("high"), Volume("large");
 
     Recommendation("Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)", 75) <- Observability("medium"), Budget("high");
-    Explanation("Cloud-native monitoring chosen for integrated observability in cloud ecosystems.")
+    Explanation("Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)", "Cloud-native monitoring chosen for integrated observability in cloud ecosystems.")
         <- Observability("medium"), Budget("high");
 
     Recommendation("ELK Stack (Elasticsearch/Logstash/Kibana)", 70) <- Observability("medium"), Budget("low");
-    Explanation("ELK stack chosen for centralized logging with limited budget.")
+    Explanation("ELK Stack (Elasticsearch/Logstash/Kibana)", "ELK stack chosen for centralized logging with limited budget.")
         <- Observability("medium"), Budget("low");
 
     Recommendation("Alerting & Incident Response (PagerDuty/OpsGenie)", 90) <- Observability("high"), SLA("high");
-    Explanation("Alerting chosen for automated incident response and SLA adherence.")
+    Explanation("Alerting & Incident Response (PagerDuty/OpsGenie)", "Alerting chosen for automated incident response and SLA adherence.")
         <- Observability("high"), SLA("high");
 
     Recommendation("FinOps Dashboards", 78) <- Observability("high"), Budget("low");
-    Explanation("FinOps dashboards chosen for cost control and resource optimization.")
+    Explanation("FinOps Dashboards", "FinOps dashboards chosen for cost control and resource optimization.")
         <- Observability("high"), Budget("low");
-}
 
-// === Profiles for fuzzy scoring in --multi mode ===
-struct ArchProfile {
-    name: &'static str,
-    volume: &'static str,
-    workload: &'static str,
-    sla: &'static str,
-    budget: &'static str,
-    observability: &'static str,
-    base: i32,
+    // === Data quality rules ===
+    Recommendation("dbt Tests", 76) <- DataQuality(level), Workload(w), (level != "low"), (w != "streaming");
+    Explanation("dbt Tests", "dbt tests chosen to assert uniqueness, null rates and relationships inside the transformations.")
+        <- DataQuality(level), Workload(w), (level != "low"), (w != "streaming");
+
+    Recommendation("Data Validation (Great Expectations)", 78) <- DataQuality("high"), Budget("low");
+    Explanation("Data Validation (Great Expectations)", "Great Expectations chosen for open-source validation suites run at every pipeline stage.")
+        <- DataQuality("high"), Budget("low");
+
+    Recommendation("Data Quality Monitoring (Soda)", 82) <- DataQuality("high"), Budget("high");
+    Explanation("Data Quality Monitoring (Soda)", "Soda chosen to monitor freshness, volume and schema drift with alerting on data contracts.")
+        <- DataQuality("high"), Budget("high");
+
+    Recommendation("Schema Registry (Confluent/Apicurio)", 84) <- DataQuality(level), Workload("streaming"), (level != "low");
+    Explanation("Schema Registry (Confluent/Apicurio)", "Schema registry chosen to enforce data contracts and block breaking schema changes at producers.")
+        <- DataQuality(level), Workload("streaming"), (level != "low");
+
+    Recommendation("Schema Registry (Confluent/Apicurio)", 84) <- DataQuality(level), Source("oltp"), (level != "low");
+    Explanation("Schema Registry (Confluent/Apicurio)", "Schema registry chosen to catch schema drift in change events from OLTP databases.")
+        <- DataQuality(level), Source("oltp"), (level != "low");
+
+    // === Data format rules ===
+    // Format(format, picked storage it applies to, explanation)
+    Format("Parquet/ORC", s, "Columnar files chosen because data is append-only and the schema is stable; ORC suits Hive tooling.")
+        <- Picked(s), (formats::is_open_format_storage(s)), Updates("none"), SchemaVolatility(v), (v != "high");
+
+    Format("Delta Lake/Apache Iceberg", s, "ACID table format chosen for MERGE and DELETE (e.g. erasure requests) without rewriting files by hand.")
+        <- Picked(s), (formats::is_open_format_storage(s)), Updates("occasional");
+
+    Format("Apache Hudi/Delta Lake (merge-on-read)", s, "Merge-on-read table format chosen for frequent upserts and deletes such as CDC streams.")
+        <- Picked(s), (formats::is_open_format_storage(s)), Updates("frequent");
+
+    Format("Apache Iceberg", s, "Iceberg chosen for schema and partition evolution without table rewrites.")
+        <- Picked(s), (formats::is_open_format_storage(s)), SchemaVolatility("high");
+
+    Format("Apache Iceberg (external tables)", s, "Iceberg external tables chosen so volatile schemas evolve outside the warehouse's managed storage.")
+        <- Picked(s), (formats::is_warehouse(s)), SchemaVolatility("high");
+
+    Format("Avro + Schema Registry", s, "Avro chosen for reader/writer schema resolution; the registry enforces compatible evolution of events.")
+        <- Picked(s), (formats::is_event_stream(s)), SchemaVolatility(v), (v != "low");
+
+    Format("Protobuf + Schema Registry", s, "Protobuf chosen for compact, strongly typed event contracts shared with services.")
+        <- Picked(s), (formats::is_event_stream(s)), SchemaVolatility("low");
+
+    Recommendation("Schema Registry (Confluent/Apicurio)", 84) <- SchemaVolatility("high"), Workload("streaming");
+    Explanation("Schema Registry (Confluent/Apicurio)", "Schema registry chosen because event schemas change often and need compatibility checks.")
+        <- SchemaVolatility("high"), Workload("streaming");
+
+    // === Security rules ===
+    Recommendation("Access Control (Apache Ranger/Lake Formation)", 84) <- SecurityPosture("multi-tenant");
+    Explanation("Access Control (Apache Ranger/Lake Formation)", "Central access control chosen to isolate tenants with fine-grained grants.")
+        <- SecurityPosture("multi-tenant");
+
+    Recommendation("Data Masking & Tokenization (Immuta/Privacera)", 82) <- SecurityPosture("pii");
+    Explanation("Data Masking & Tokenization (Immuta/Privacera)", "Masking chosen to enforce column- and row-level policies on PII.")
+        <- SecurityPosture("pii");
+
+    Recommendation("Encryption & Key Management (KMS/HSM)", 85) <- SecurityPosture("pii");
+    Recommendation("Encryption & Key Management (KMS/HSM)", 85) <- SecurityPosture("zero-trust");
+    Explanation("Encryption & Key Management (KMS/HSM)", "Customer-managed keys chosen so that PII stays encrypted under keys you control.")
+        <- SecurityPosture("pii");
+    Explanation("Encryption & Key Management (KMS/HSM)", "Customer-managed keys chosen so that a zero-trust platform never relies on provider-held keys.")
+        <- SecurityPosture("zero-trust");
+
+    Recommendation("Network Isolation (VPC/Private Link)", 82) <- SecurityPosture("zero-trust");
+    Explanation("Network Isolation (VPC/Private Link)", "Network isolation chosen to remove public endpoints and segment every data service.")
+        <- SecurityPosture("zero-trust");
+
+    Recommendation("Secrets Management (HashiCorp Vault)", 78) <- SecurityPosture("zero-trust");
+    Explanation("Secrets Management (HashiCorp Vault)", "Vault chosen for short-lived, audited credentials instead of static passwords.")
+        <- SecurityPosture("zero-trust");
+
+    // SecurityControl(control, picked data platform it applies to, how)
+    SecurityControl("Fine-grained access control", s, "Tenant-scoped grants on catalogs, tables and storage prefixes (Ranger/Lake Formation/Unity Catalog).")
+        <- Picked(s), (formats::is_open_format_storage(s)), SecurityPosture("multi-tenant");
+    SecurityControl("Fine-grained access control", s, "A role per tenant with secure views or row access policies keyed on the tenant id.")
+        <- Picked(s), (formats::is_warehouse(s)), SecurityPosture("multi-tenant");
+    SecurityControl("Fine-grained access control", s, "Per-tenant topic prefixes with ACLs bound to each tenant's service principals.")
+        <- Picked(s), (formats::is_event_stream(s)), SecurityPosture("multi-tenant");
+
+    SecurityControl("Column/row-level security", s, "Row filters and column masks defined in the table catalog, applied to every engine.")
+        <- Picked(s), (formats::is_open_format_storage(s)), SecurityPosture("pii");
+    SecurityControl("Column/row-level security", s, "Native row access policies and dynamic column masking on PII columns.")
+        <- Picked(s), (formats::is_warehouse(s)), SecurityPosture("pii");
+    SecurityControl("Column/row-level security", s, "PII fields encrypted or tokenized in the event payload before it is produced.")
+        <- Picked(s), (formats::is_event_stream(s)), SecurityPosture("pii");
+
+    SecurityControl("Key management", s, "Customer-managed KMS keys with rotation for data at rest, envelope encryption per dataset.")
+        <- Picked(s), (security::is_data_platform(s)), SecurityPosture("pii");
+    SecurityControl("Key management", s, "Customer-managed KMS keys with rotation for data at rest, envelope encryption per dataset.")
+        <- Picked(s), (security::is_data_platform(s)), SecurityPosture("zero-trust");
+
+    SecurityControl("Network isolation", s, "Private endpoints only, no public ingress; mTLS and workload identities between services.")
+        <- Picked(s), (security::is_data_platform(s)), SecurityPosture("zero-trust");
+
+    // === Risk and anti-pattern warnings ===
+    // Warning(message, severity, mitigation) for architectures the report picked.
+    Warning("Hadoop/Spark with high SLA and streaming workload: latency risk", "high",
+            "Use Kafka/Flink or Spark Structured Streaming for the streaming path.")
+        <- Picked("Hadoop/Spark"), SLA("high"), Workload("streaming");
+
+    Warning("Self-managed Hadoop/Spark without JVM skills: tuning and upgrade risk", "medium",
+            "Prefer Managed Spark (Databricks/EMR) or train the team before go-live.")
+        <- Picked("Hadoop/Spark"), MissingSkill("jvm");
+
+    Warning("Data Mesh with a small team: organizational overhead", "high",
+            "Start with a central Lakehouse and move to domain ownership as teams grow.")
+        <- Picked("Data Mesh"), TeamSize("small");
+
+    Warning("Data Mesh with low operational maturity: inconsistent domain platforms", "medium",
+            "Provide a self-serve platform team and shared standards before splitting domains.")
+        <- Picked("Data Mesh"), OpsMaturity("low");
+
+    Warning("Kafka/Flink for a batch workload: always-on cluster for periodic jobs", "medium",
+            "Run scheduled batch jobs (ETL Pipelines, Managed Spark) instead.")
+        <- Picked("Kafka/Flink"), Workload("batch");
+
+    Warning("Hybrid Cloud with a low budget: duplicated infrastructure cost", "high",
+            "Pick one primary cloud and keep on-premises only for data that must stay there.")
+        <- Picked("Hybrid Cloud"), Budget("low");
+
+    Warning("Large data lake without data-quality checks: data swamp risk", "medium",
+            "Add a table format, a catalog and dbt tests or Great Expectations.")
+        <- Picked("Data Lake (S3/ADLS)"), Volume("large"), DataQuality("low");
+
+    Warning("Plain data lake with frequent updates/deletes: costly file rewrites", "high",
+            "Use a table format (Delta Lake/Hudi) or a Lakehouse.")
+        <- Picked("Data Lake (S3/ADLS)"), Updates("frequent");
+
+    Warning("Key-value store for ad-hoc queries: no secondary access paths", "medium",
+            "Serve ad-hoc queries from a warehouse or Federated SQL next to the key-value store.")
+        <- Picked("Key-Value Store (Redis/Cassandra/DynamoDB)"), AccessPattern("adhoc");
+
+    Warning("Regulated data with low observability: audit gaps", "high",
+            "Add audit logging and data lineage before processing regulated data.")
+        <- Compliance(_), Observability("low");
+
+    Warning("Volatile event schemas without data contracts: downstream breakage", "high",
+            "Introduce a schema registry with compatibility checks at producers.")
+        <- Workload("streaming"), SchemaVolatility("high"), DataQuality("low");
+
+    // === Compliance rules ===
+    Recommendation("Governance Layer (Collibra/Alation)", 70) <- Compliance(_);
+    Explanation("Governance Layer (Collibra/Alation)", "Governance chosen to catalog regulated data and document processing purposes.")
+        <- Compliance(_);
+
+    Recommendation("Governance Layer (Collibra/Alation)", 70) <- Residency(_);
+    Explanation("Governance Layer (Collibra/Alation)", "Governance chosen to track where resident data is stored and processed.")
+        <- Residency(_);
+
+    Recommendation("Audit Logging (CloudTrail/Audit Vault)", 80) <- Compliance(_);
+    Explanation("Audit Logging (CloudTrail/Audit Vault)", "Audit logging chosen to prove who accessed regulated data and when.")
+        <- Compliance(_);
+
+    Recommendation("Data Masking & Tokenization (Immuta/Privacera)", 82) <- Compliance("gdpr");
+    Explanation("Data Masking & Tokenization (Immuta/Privacera)", "Masking chosen to pseudonymize personal data in analytics under GDPR.")
+        <- Compliance("gdpr");
+
+    Recommendation("Data Masking & Tokenization (Immuta/Privacera)", 82) <- Compliance("pci");
+    Explanation("Data Masking & Tokenization (Immuta/Privacera)", "Tokenization chosen to keep cardholder data out of analytics under PCI DSS.")
+        <- Compliance("pci");
+
+    Recommendation("Encryption & Key Management (KMS/HSM)", 85) <- Compliance("hipaa");
+    Explanation("Encryption & Key Management (KMS/HSM)", "Encryption chosen to protect health information at rest and in transit under HIPAA.")
+        <- Compliance("hipaa");
+
+    Recommendation("Encryption & Key Management (KMS/HSM)", 85) <- Compliance("pci");
+    Explanation("Encryption & Key Management (KMS/HSM)", "Encryption with managed keys chosen for cardholder data under PCI DSS.")
+        <- Compliance("pci");
+
+    // === Latency and availability rules ===
+    Recommendation("Key-Value Store (Redis/Cassandra/DynamoDB)", 87) <- LatencyClass("realtime");
+    Explanation("Key-Value Store (Redis/Cassandra/DynamoDB)", "Key-value store chosen to serve precomputed results within sub-100 ms latency.")
+        <- LatencyClass("realtime");
+
+    Recommendation("Kafka/Flink", 90) <- LatencyClass("realtime");
+    Explanation("Kafka/Flink", "Kafka/Flink chosen to compute results continuously for real-time serving.")
+        <- LatencyClass("realtime");
+
+    Recommendation("Kafka/Flink", 90) <- LatencyClass("streaming");
+    Explanation("Kafka/Flink", "Kafka/Flink chosen for second-level end-to-end latency.")
+        <- LatencyClass("streaming");
+
+    Recommendation("Micro-batch (Spark Structured Streaming)", 78) <- LatencyClass("micro-batch");
+    Explanation("Micro-batch (Spark Structured Streaming)", "Micro-batching chosen for minute-level freshness without a full streaming stack.")
+        <- LatencyClass("micro-batch");
+
+    Recommendation("Orchestration (Airflow/Prefect)", 65) <- LatencyClass("batch");
+    Explanation("Orchestration (Airflow/Prefect)", "Orchestration chosen to schedule loads that only need hourly or daily freshness.")
+        <- LatencyClass("batch");
+
+    Recommendation("Alerting & Incident Response (PagerDuty/OpsGenie)", 90) <- AvailabilityClass("critical");
+    Explanation("Alerting & Incident Response (PagerDuty/OpsGenie)", "Alerting chosen because four nines leave under an hour of downtime per year.")
+        <- AvailabilityClass("critical");
+
+    Recommendation("Hybrid Cloud", 80) <- AvailabilityClass("critical"), Budget("high");
+    Explanation("Hybrid Cloud", "Hybrid Cloud chosen to survive a provider or region outage at four nines and above.")
+        <- AvailabilityClass("critical"), Budget("high");
+
+    // === Team skills and operational maturity rules ===
+    Recommendation("Managed Spark (Databricks/EMR)", 82) <- Workload("batch"), Volume("large"), OpsMaturity("low");
+    Explanation("Managed Spark (Databricks/EMR)", "Managed Spark chosen so large batch jobs run without a cluster operations team.")
+        <- Workload("batch"), Volume("large"), OpsMaturity("low");
+
+    Recommendation("Managed Spark (Databricks/EMR)", 82) <- Workload("batch"), Volume("large"), MissingSkill("jvm");
+    Explanation("Managed Spark (Databricks/EMR)", "Managed Spark chosen because notebooks and PySpark avoid JVM tuning the team cannot do.")
+        <- Workload("batch"), Volume("large"), MissingSkill("jvm");
+
+    Recommendation("Managed Kafka (Confluent Cloud/MSK)", 85) <- Workload("streaming"), OpsMaturity("low");
+    Explanation("Managed Kafka (Confluent Cloud/MSK)", "Managed Kafka chosen because brokers, upgrades and rebalancing are run by the provider.")
+        <- Workload("streaming"), OpsMaturity("low");
+
+    Recommendation("Managed Kafka (Confluent Cloud/MSK)", 85) <- Workload("streaming"), TeamSize("small");
+    Explanation("Managed Kafka (Confluent Cloud/MSK)", "Managed Kafka chosen because a small team cannot staff an on-call rotation for brokers.")
+        <- Workload("streaming"), TeamSize("small");
+
+    Recommendation("Managed ML Platform (SageMaker/Vertex AI)", 86) <- Workload("mixed"), Budget("high"), MissingSkill("kubernetes");
+    Explanation("Managed ML Platform (SageMaker/Vertex AI)", "Managed ML platform chosen because Kubeflow needs Kubernetes skills the team lacks.")
+        <- Workload("mixed"), Budget("high"), MissingSkill("kubernetes");
+
+    Recommendation("Cloud DW (BigQuery/Snowflake)", 85) <- Workload("mixed"), OpsMaturity("low");
+    Explanation("Cloud DW (BigQuery/Snowflake)", "Cloud DW chosen because a serverless warehouse needs no cluster operations.")
+        <- Workload("mixed"), OpsMaturity("low");
+
+    Recommendation("Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)", 75) <- Observability("high"), OpsMaturity("low");
+    Explanation("Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)", "Cloud-native monitoring chosen over a self-hosted stack for a team with low ops maturity.")
+        <- Observability("high"), OpsMaturity("low");
+
+    // === Serving layer rules ===
+    Recommendation("Real-time OLAP (ClickHouse/Druid/Pinot)", 88) <- AccessPattern("olap");
+    Explanation("Real-time OLAP (ClickHouse/Druid/Pinot)", "Real-time OLAP chosen for sub-second aggregations on interactive dashboards.")
+        <- AccessPattern("olap");
+
+    Recommendation("Real-time OLAP (ClickHouse/Druid/Pinot)", 88) <- AccessPattern("olap"), Workload("streaming");
+    Explanation("Real-time OLAP (ClickHouse/Druid/Pinot)", "Real-time OLAP chosen because it ingests streams directly and makes events queryable in seconds.")
+        <- AccessPattern("olap"), Workload("streaming");
+
+    Recommendation("Key-Value Store (Redis/Cassandra/DynamoDB)", 87) <- AccessPattern("lookup");
+    Explanation("Key-Value Store (Redis/Cassandra/DynamoDB)", "Key-value store chosen for millisecond point lookups by key.")
+        <- AccessPattern("lookup");
+
+    Recommendation("Search Engine (Elasticsearch/OpenSearch)", 84) <- AccessPattern("search");
+    Explanation("Search Engine (Elasticsearch/OpenSearch)", "Search engine chosen for full-text search, relevance ranking and faceting.")
+        <- AccessPattern("search");
+
+    Recommendation("Federated SQL (Trino/Presto)", 80) <- AccessPattern("adhoc");
+    Explanation("Federated SQL (Trino/Presto)", "Federated SQL chosen for ad-hoc queries across the lake and operational sources without copying data.")
+        <- AccessPattern("adhoc");
+
+    Recommendation("Cloud DW (BigQuery/Snowflake)", 85) <- AccessPattern("adhoc"), Budget("high");
+    Explanation("Cloud DW (BigQuery/Snowflake)", "Cloud DW chosen for ad-hoc SQL by analysts without capacity planning.")
+        <- AccessPattern("adhoc"), Budget("high");
+
+    // === Ingestion rules ===
+    Recommendation("Change Data Capture (Debezium)", 86) <- Source("oltp"), Workload("streaming");
+    Explanation("Change Data Capture (Debezium)", "CDC chosen to stream row-level changes from OLTP databases without extra query load.")
+        <- Source("oltp"), Workload("streaming");
+
+    Recommendation("Change Data Capture (Debezium)", 86) <- Source("oltp"), Workload("mixed");
+    Explanation("Change Data Capture (Debezium)", "CDC chosen to keep analytical copies of OLTP tables continuously in sync.")
+        <- Source("oltp"), Workload("mixed");
+
+    Recommendation("Kafka Connect", 80) <- Source("oltp"), Workload("streaming");
+    Explanation("Kafka Connect", "Kafka Connect chosen to run the Debezium connectors and sink change events.")
+        <- Source("oltp"), Workload("streaming");
+
+    Recommendation("Managed ELT (Fivetran/Airbyte)", 80) <- Source("oltp"), Workload("batch");
+    Explanation("Managed ELT (Fivetran/Airbyte)", "Managed ELT chosen for incremental batch replication of OLTP tables.")
+        <- Source("oltp"), Workload("batch");
+
+    Recommendation("Managed ELT (Fivetran/Airbyte)", 80) <- Source("saas");
+    Explanation("Managed ELT (Fivetran/Airbyte)", "Managed ELT chosen because maintained connectors absorb SaaS API changes and rate limits.")
+        <- Source("saas");
+
+    Recommendation("Batch File Drops (SFTP/S3 Landing Zone)", 70) <- Source("files");
+    Explanation("Batch File Drops (SFTP/S3 Landing Zone)", "File drops chosen to land partner and export files in a versioned landing zone.")
+        <- Source("files");
+
+    Recommendation("MQTT Bridge (EMQX/HiveMQ)", 84) <- Source("iot");
+    Explanation("MQTT Bridge (EMQX/HiveMQ)", "MQTT bridge chosen to accept device telemetry over lightweight, unreliable links.")
+        <- Source("iot");
+
+    Recommendation("Kafka Connect", 80) <- Source("iot"), Workload("streaming");
+    Explanation("Kafka Connect", "Kafka Connect chosen to forward MQTT topics into the streaming platform.")
+        <- Source("iot"), Workload("streaming");
+
+    Recommendation("Log Shippers (Fluent Bit/Vector)", 76) <- Source("logs");
+    Explanation("Log Shippers (Fluent Bit/Vector)", "Log shippers chosen to collect, parse and buffer application logs at the edge.")
+        <- Source("logs");
+
+    // === Machine learning rules ===
+    Recommendation("ML Platform (Kubeflow/MLflow)", 88) <- MlNeed("training"), Budget("high");
+    Explanation("ML Platform (Kubeflow/MLflow)", "ML Platform chosen to run repeatable training pipelines on owned infrastructure.")
+        <- MlNeed("training"), Budget("high");
+
+    Recommendation("Managed ML Platform (SageMaker/Vertex AI)", 86) <- MlNeed("training"), Budget("low");
+    Explanation("Managed ML Platform (SageMaker/Vertex AI)", "Managed ML platform chosen for pay-per-job training without an idle cluster.")
+        <- MlNeed("training"), Budget("low");
+
+    Recommendation("GPU Training Cluster (Ray/KubeRay)", 84) <- MlNeed("deep-learning"), Budget("high");
+    Explanation("GPU Training Cluster (Ray/KubeRay)", "GPU cluster chosen because deep learning training is impractical on CPUs.")
+        <- MlNeed("deep-learning"), Budget("high");
+
+    Recommendation("Managed ML Platform (SageMaker/Vertex AI)", 86) <- MlNeed("deep-learning"), Budget("low");
+    Explanation("Managed ML Platform (SageMaker/Vertex AI)", "Managed ML platform chosen to rent GPUs (spot) per training job instead of owning them.")
+        <- MlNeed("deep-learning"), Budget("low");
+
+    Recommendation("Experiment Tracking (MLflow/W&B)", 78) <- MlNeed("experiments");
+    Explanation("Experiment Tracking (MLflow/W&B)", "Experiment tracking chosen to compare runs, parameters and model versions.")
+        <- MlNeed("experiments");
+
+    Recommendation("Experiment Tracking (MLflow/W&B)", 78) <- MlNeed("training"), Observability("high");
+    Explanation("Experiment Tracking (MLflow/W&B)", "Experiment tracking chosen so that every trained model is reproducible and auditable.")
+        <- MlNeed("training"), Observability("high");
+
+    Recommendation("Feature Store (Feast/Tecton)", 84) <- MlNeed("features");
+    Explanation("Feature Store (Feast/Tecton)", "Feature store chosen to share features between teams and keep training and serving consistent.")
+        <- MlNeed("features");
+
+    Explanation("Feature Store (Feast/Tecton)", "Feature store chosen to serve the same features online at inference time.")
+        <- MlNeed("features"), MlNeed("inference");
+
+    Recommendation("Model Serving (KServe/Seldon)", 86) <- MlNeed("inference");
+    Explanation("Model Serving (KServe/Seldon)", "Model serving chosen for versioned, autoscaled online inference endpoints.")
+        <- MlNeed("inference");
+
+    Recommendation("Model Serving (KServe/Seldon)", 86) <- MlNeed("inference"), LatencyClass("realtime");
+    Explanation("Model Serving (KServe/Seldon)", "Model serving chosen to keep online predictions within the real-time latency target.")
+        <- MlNeed("inference"), LatencyClass("realtime");
+
+    Recommendation("Key-Value Store (Redis/Cassandra/DynamoDB)", 87) <- MlNeed("features"), MlNeed("inference");
+    Explanation("Key-Value Store (Redis/Cassandra/DynamoDB)", "Key-value store chosen as the online feature store backend.")
+        <- MlNeed("features"), MlNeed("inference");
+
+    Recommendation("Vector Database (Milvus/pgvector/Pinecone)", 84) <- MlNeed("retrieval");
+    Explanation("Vector Database (Milvus/pgvector/Pinecone)", "Vector database chosen for embedding similarity search in retrieval-augmented applications.")
+        <- MlNeed("retrieval");
 }
 
 fn similarity_score(
@@ -156,10 +575,15 @@ fn similarity_score(
 }
 
 fn read_input(prompt: &str) -> String {
+    read_line(prompt).to_lowercase()
+}
+
+/// Reads an answer as typed, e.g. names.
+fn read_line(prompt: &str) -> String {
     println!("{}", prompt);
     let mut input = String::new();
     std::io::stdin().read_line(&mut input).unwrap();
-    input.trim().to_lowercase()
+    input.trim().to_string()
 }
 
 fn read_weight(prompt: &str, default: i32) -> i32 {
@@ -172,254 +596,772 @@ fn read_weight(prompt: &str, default: i32) -> i32 {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+fn read_number(prompt: &str, default: f64) -> f64 {
+    println!("{} (default {}):", prompt, default);
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input).unwrap();
+    input
+        .trim()
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .unwrap_or(default)
+}
 
-    println!("=== Big Data Architecture Advisor CLI ===");
-    if args.len() > 1 && args[1] == "--multi" {
-        println!("*** MULTI Recommendations Mode ***\n");
+fn has_flag(args: &[String], flag: &str) -> bool {
+    args.iter().skip(1).any(|a| a == flag)
+}
+
+/// The argument after `flag`, unless it is another flag.
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .skip(1)
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 2))
+        .map(|s| s.as_str())
+        .filter(|s| !s.starts_with("--"))
+}
+
+/// The first flag of `VALUE_FLAGS` given without a value.
+fn missing_value<'a>(args: &[String], flags: &[&'a str]) -> Option<&'a str> {
+    flags
+        .iter()
+        .find(|flag| has_flag(args, flag) && flag_value(args, flag).is_none())
+        .copied()
+}
+
+fn leak(value: &str) -> &'static str {
+    Box::leak(value.to_string().into_boxed_str())
+}
+
+type RuleOutput = (
+    HashSet<Recommendation>,
+    HashSet<Explanation>,
+    HashSet<Format>,
+    HashSet<SecurityControl>,
+    HashSet<Warning>,
+);
+
+/// Feeds the scenario and the architectures already picked by the report
+/// into the crepe rule base and returns its raw output.
+fn run_rules(scenario: &Scenario, picked: &[&'static str]) -> RuleOutput {
+    let mut runtime = Crepe::new();
+
+    runtime.extend(&[Volume(leak(&scenario.volume))]);
+    runtime.extend(&[Workload(leak(&scenario.workload))]);
+    runtime.extend(&[SLA(leak(&scenario.sla))]);
+    runtime.extend(&[Budget(leak(&scenario.budget))]);
+    runtime.extend(&[Observability(leak(&scenario.observability))]);
+    runtime.extend(
+        compliance::known_regimes(scenario)
+            .into_iter()
+            .map(|c| Compliance(leak(c))),
+    );
+    runtime.extend(compliance::region(scenario).map(|r| Residency(leak(r))));
+    runtime.extend(
+        scenario
+            .latency_ms
+            .map(|ms| LatencyClass(latency::latency_class(ms))),
+    );
+    runtime.extend(
+        scenario
+            .availability
+            .map(|pct| AvailabilityClass(latency::availability_class(pct))),
+    );
+    runtime.extend(
+        scenario
+            .team_size
+            .map(|n| TeamSize(team::team_size_class(n))),
+    );
+    runtime.extend(scenario.ops_maturity.iter().map(|m| OpsMaturity(leak(m))));
+    runtime.extend(team::missing_skills(scenario).into_iter().map(MissingSkill));
+    runtime.extend(
+        scenario
+            .access_patterns
+            .iter()
+            .map(|p| AccessPattern(leak(p))),
+    );
+    runtime.extend(scenario.sources.iter().map(|s| Source(leak(s))));
+    runtime.extend(scenario.ml_needs.iter().map(|n| MlNeed(leak(n))));
+    runtime.extend(&[DataQuality(leak(scenario.data_quality_level()))]);
+    runtime.extend(&[SchemaVolatility(leak(scenario.schema_volatility_level()))]);
+    runtime.extend(&[Updates(leak(scenario.updates_level()))]);
+    runtime.extend(
+        scenario
+            .security
+            .iter()
+            .filter(|p| security::POSTURES.contains(&p.as_str()))
+            .map(|p| SecurityPosture(leak(p))),
+    );
+    runtime.extend(picked.iter().map(|p| Picked(p)));
+
+    runtime.run()
+}
+
+/// Runs the crepe rule base and returns weight-adjusted recommendations, best first.
+fn evaluate_strict(
+    scenario: &Scenario,
+    weights: &Weights,
+) -> (Vec<Recommendation>, Vec<Explanation>) {
+    let (recs, expls, _, _, _) = run_rules(scenario, &[]);
+
+    // Several rules may recommend the same architecture with different
+    // base scores; the strongest one stands for it.
+    let mut best: BTreeMap<&'static str, i32> = BTreeMap::new();
+    for Recommendation(r, base) in recs {
+        if compliance::satisfies_residency(r, scenario) {
+            let entry = best.entry(r).or_insert(base);
+            *entry = (*entry).max(base);
+        }
+    }
+
+    let weight_sum = weights.sum();
+    let mut recs_adjusted: Vec<Recommendation> = best
+        .into_iter()
+        .map(|(r, base)| {
+            let score = base * weight_sum / 10 + adjustments::total(scenario, weights, r);
+            Recommendation(r, score)
+        })
+        .collect();
+
+    recs_adjusted.sort_by_key(|r| (std::cmp::Reverse(r.1), r.0));
+
+    // Explanations follow the ranking so reports and ADRs come out the same every run.
+    let mut expls: Vec<Explanation> = expls
+        .into_iter()
+        .filter(|Explanation(r, _)| compliance::satisfies_residency(r, scenario))
+        .collect();
+    expls.sort_by_key(|Explanation(r, e)| {
+        let position = recs_adjusted.iter().position(|Recommendation(n, _)| n == r);
+        (position.unwrap_or(usize::MAX), *r, *e)
+    });
+
+    (recs_adjusted, expls)
+}
+
+/// Table formats and serialization for the picked storage, grouped by storage.
+fn data_formats(scenario: &Scenario, picked: &[&'static str]) -> Vec<Format> {
+    let (_, _, formats, _, _) = run_rules(scenario, picked);
+    let mut formats: Vec<Format> = formats
+        .into_iter()
+        .filter(|Format(_, s, _)| compliance::satisfies_residency(s, scenario))
+        .collect();
+    formats.sort_by_key(|Format(f, s, _)| (*s, *f));
+    formats
+}
+
+/// Chosen recommendations that store or stream data, which formats and
+/// security controls apply to.
+fn storage_picks(chosen: &[(&'static str, i32)]) -> Vec<&'static str> {
+    chosen
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| security::is_data_platform(name))
+        .collect()
+}
+
+/// Security controls for the picked data platforms, grouped by platform.
+fn security_controls(scenario: &Scenario, picked: &[&'static str]) -> Vec<SecurityControl> {
+    let (_, _, _, controls, _) = run_rules(scenario, picked);
+    let mut controls: Vec<SecurityControl> = controls
+        .into_iter()
+        .filter(|SecurityControl(_, s, _)| compliance::satisfies_residency(s, scenario))
+        .collect();
+    controls.sort_by_key(|SecurityControl(c, s, _)| (*s, *c));
+    controls
+}
+
+/// Risks and anti-patterns of the picked architectures in the scenario, most severe first.
+fn risk_warnings(scenario: &Scenario, picked: &[(&'static str, i32)]) -> Vec<Warning> {
+    let names: Vec<_> = picked.iter().map(|(name, _)| *name).collect();
+    let (_, _, _, _, warnings) = run_rules(scenario, &names);
+    let mut warnings: Vec<Warning> = warnings.into_iter().collect();
+    warnings
+        .sort_by_key(|Warning(message, severity, _)| (report::severity_rank(severity), *message));
+    warnings
+}
+
+/// Architectures with scores, best first, in the selected mode.
+fn rank(
+    scenario: &Scenario,
+    weights: &Weights,
+    multi: bool,
+    strategy: &dyn RankingStrategy,
+) -> Vec<(&'static str, i32)> {
+    if multi {
+        evaluate_multi_with(scenario, weights, strategy)
     } else {
-        println!("*** STRICT Rule-Based Mode (using Crepe library) ***\n");
+        evaluate_strict(scenario, weights)
+            .0
+            .iter()
+            .map(|Recommendation(r, s)| (*r, *s))
+            .collect()
+    }
+}
+
+/// Scores every catalog profile against the scenario, best first.
+fn evaluate_multi(scenario: &Scenario, weights: &Weights) -> Vec<(&'static str, i32)> {
+    evaluate_multi_with(scenario, weights, &ranking::Additive)
+}
+
+/// Scores every catalog profile with the given ranking strategy, best first.
+fn evaluate_multi_with(
+    scenario: &Scenario,
+    weights: &Weights,
+    strategy: &dyn RankingStrategy,
+) -> Vec<(&'static str, i32)> {
+    let candidates: Vec<ArchProfile> = profiles_for(scenario)
+        .into_iter()
+        .filter(|p| compliance::satisfies_residency(p.name, scenario))
+        .collect();
+
+    let mut scored: Vec<_> = candidates
+        .iter()
+        .zip(strategy.scores(&candidates, scenario, weights))
+        .map(|(p, score)| {
+            (
+                p.name,
+                score + adjustments::total(scenario, weights, p.name),
+            )
+        })
+        .collect();
+
+    scored.sort_by_key(|s| std::cmp::Reverse(s.1));
+    scored
+}
+
+/// Typed membership vectors for fuzzy mode; empty answers are derived later.
+fn read_memberships() -> BTreeMap<String, fuzzy::Membership> {
+    fuzzy::DIMENSIONS
+        .iter()
+        .filter_map(|dim| {
+            let answer = read_input(&format!(
+                "Enter {} membership (e.g. medium:0.6,large:0.4; empty to derive):",
+                dim
+            ));
+            if answer.is_empty() {
+                return None;
+            }
+            match fuzzy::parse_membership(&answer) {
+                Some(m) => Some((dim.to_string(), m)),
+                None => {
+                    println!("! Invalid membership ignored: {}", answer);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Derives a weight set from the 10 AHP pairwise comparisons of the criteria.
+fn read_ahp_weights(who: &str) -> Weights {
+    let suffix = if who.is_empty() {
+        String::new()
+    } else {
+        format!(" (for {})", who)
+    };
+    let judgments: Vec<f64> = ahp::pairs()
+        .into_iter()
+        .map(|(i, j)| loop {
+            let answer = read_input(&format!(
+                "How much more important is {} than {}{}? (1-9, 1/3 for less; default 1):",
+                ahp::CRITERIA[i],
+                ahp::CRITERIA[j],
+                suffix
+            ));
+            if answer.is_empty() {
+                break 1.0;
+            }
+            match ahp::parse_judgment(&answer) {
+                Some(value) => break value,
+                None => println!("! Invalid judgment {}: use 1-9 or 1/2-1/9", answer),
+            }
+        })
+        .collect();
+    let result = ahp::derive(&judgments);
+    ahp::print(&result);
+    result.weights
+}
+
+/// Reads one weight set; `who` names the stakeholder answering, if any.
+fn read_weights(who: &str, pairwise: bool) -> Weights {
+    if pairwise {
+        return read_ahp_weights(who);
     }
+    let prompt = |criterion: &str| {
+        if who.is_empty() {
+            format!("Enter {} weight", criterion)
+        } else {
+            format!("Enter {} weight for {}", criterion, who)
+        }
+    };
+    Weights {
+        sla: read_weight(&prompt("SLA"), 5),
+        budget: read_weight(&prompt("Budget"), 3),
+        volume: read_weight(&prompt("Volume"), 2),
+        workload: read_weight(&prompt("Workload"), 1),
+        observability: read_weight(&prompt("Observability"), 4),
+    }
+}
 
-    let volume = read_input("Enter data volume (small/medium/large):");
+fn read_stakeholders(pairwise: bool) -> Vec<Stakeholder> {
+    parse_names(&read_line(
+        "Enter stakeholder names (comma-separated; empty for a single weight set):",
+    ))
+    .into_iter()
+    .map(|name| Stakeholder {
+        weights: read_weights(&name, pairwise),
+        name,
+    })
+    .collect()
+}
+
+/// Prompts for every scenario input; optional answers may be left empty.
+fn read_scenario(prices: &PriceTable) -> Scenario {
+    let (volume, volume_tb) = parse_volume(&read_input(
+        "Enter data volume (small/medium/large or size in TB, e.g. 40tb):",
+    ));
     let workload = read_input("Enter workload type (batch/streaming/mixed):");
     let sla = read_input("Enter SLA requirement (low/high):");
-    let budget = read_input("Enter budget (low/high):");
+    let (budget, budget_monthly) = parse_budget(
+        &read_input("Enter budget (low/high or monthly amount, e.g. 15000):"),
+        prices.high_budget_threshold,
+    );
     let observability = read_input("Enter observability requirement (low/medium/high):");
+    let compliance = parse_list(&read_input(
+        "Enter compliance regimes (gdpr/hipaa/pci, comma-separated, empty for none):",
+    ));
+    let residency = Some(read_input(
+        "Enter data residency region (eu/us/uk/apac/cn, empty for none):",
+    ))
+    .filter(|r| !r.is_empty() && r != "any");
+    let latency_ms = latency::parse_latency(&read_input(
+        "Enter end-to-end latency target (e.g. 100ms, 5s, 15m, 2h; empty to skip):",
+    ));
+    let availability = latency::parse_availability(&read_input(
+        "Enter availability target (e.g. 99.95 or 4 nines; empty to skip):",
+    ));
 
-    let sla_w = read_weight("Enter SLA weight", 5);
-    let budget_w = read_weight("Enter Budget weight", 3);
-    let volume_w = read_weight("Enter Volume weight", 2);
-    let workload_w = read_weight("Enter Workload weight", 1);
-    let observability_w = read_weight("Enter Observability weight", 4);
+    let team_size = read_input("Enter team size (number of engineers; empty to skip):")
+        .parse::<u32>()
+        .ok();
+    let skills = parse_list(&read_input(
+        "Enter team skills (jvm/python/sql/kubernetes, comma-separated; empty to skip):",
+    ));
+    let ops_maturity = Some(read_input(
+        "Enter operational maturity (low/medium/high; empty to skip):",
+    ))
+    .filter(|m| !m.is_empty());
+    let access_patterns = parse_list(&read_input(
+        "Enter access patterns (olap/lookup/search/adhoc, comma-separated; empty to skip):",
+    ));
+    let sources = parse_list(&read_input(
+        "Enter data sources (oltp/saas/files/iot/logs, comma-separated; empty to skip):",
+    ));
+    let ml_needs = parse_list(&read_input(
+        "Enter ML needs (training/deep-learning/inference/features/retrieval/experiments, comma-separated; empty to skip):",
+    ));
+    let data_quality = Some(read_input(
+        "Enter data-quality requirement (low/medium/high; empty to follow observability):",
+    ))
+    .filter(|s| !s.is_empty());
+    let schema_volatility = Some(read_input(
+        "Enter schema volatility (low/medium/high; empty for low):",
+    ))
+    .filter(|s| !s.is_empty());
+    let updates = Some(read_input(
+        "Enter update/delete needs (none/occasional/frequent; empty for none):",
+    ))
+    .filter(|s| !s.is_empty());
+    let security = parse_list(&read_input(
+        "Enter security posture (multi-tenant/pii/zero-trust, comma-separated; empty to skip):",
+    ));
 
-    if args.len() > 1 && args[1] == "--multi" {
-        let profiles = vec![
-            ArchProfile {
-                name: "Lakehouse",
-                volume: "large",
-                workload: "mixed",
-                sla: "high",
-                budget: "high",
-                observability: "high",
-                base: 95,
-            },
-            ArchProfile {
-                name: "Kafka/Flink",
-                volume: "any",
-                workload: "streaming",
-                sla: "high",
-                budget: "any",
-                observability: "high",
-                base: 90,
-            },
-            ArchProfile {
-                name: "Cloud-native Monitoring (CloudWatch/Stackdriver/Azure Monitor)",
-                volume: "any",
-                workload: "any",
-                sla: "any",
-                budget: "high",
-                observability: "medium",
-                base: 75,
-            },
-            ArchProfile {
-                name: "Data Mesh",
-                volume: "large",
-                workload: "mixed",
-                sla: "high",
-                budget: "any",
-                observability: "high",
-                base: 90,
-            },
-            ArchProfile {
-                name: "ML Platform (Kubeflow/MLflow)",
-                volume: "any",
-                workload: "mixed",
-                sla: "high",
-                budget: "high",
-                observability: "high",
-                base: 88,
-            },
-            ArchProfile {
-                name: "Hybrid Cloud",
-                volume: "large",
-                workload: "mixed",
-                sla: "high",
-                budget: "any",
-                observability: "high",
-                base: 80,
-            },
-            ArchProfile {
-                name: "Data Lake (S3/ADLS)",
-                volume: "large",
-                workload: "batch",
-                sla: "high",
-                budget: "high",
-                observability: "high",
-                base: 75,
-            },
-            ArchProfile {
-                name: "Orchestration (Airflow/Prefect)",
-                volume: "any",
-                workload: "batch",
-                sla: "high",
-                budget: "any",
-                observability: "medium",
-                base: 65,
-            },
-            ArchProfile {
-                name: "Governance Layer (Collibra/Alation)",
-                volume: "large",
-                workload: "any",
-                sla: "high",
-                budget: "any",
-                observability: "high",
-                base: 70,
-            },
-            ArchProfile {
-                name: "Monitoring Stack (Prometheus/Grafana)",
-                volume: "any",
-                workload: "any",
-                sla: "any",
-                budget: "any",
-                observability: "high",
-                base: 85,
-            },
-            ArchProfile {
-                name: "Distributed Tracing (OpenTelemetry/Jaeger)",
-                volume: "any",
-                workload: "streaming",
-                sla: "any",
-                budget: "any",
-                observability: "high",
-                base: 88,
-            },
-            ArchProfile {
-                name: "Data Lineage Tools (OpenLineage/Marquez)",
-                volume: "large",
-                workload: "any",
-                sla: "any",
-                budget: "any",
-                observability: "high",
-                base: 80,
-            },
-            ArchProfile {
-                name: "ELK Stack (Elasticsearch/Logstash/Kibana)",
-                volume: "any",
-                workload: "any",
-                sla: "any",
-                budget: "low",
-                observability: "medium",
-                base: 70,
-            },
-            ArchProfile {
-                name: "Alerting & Incident Response (PagerDuty/OpsGenie)",
-                volume: "any",
-                workload: "any",
-                sla: "high",
-                budget: "any",
-                observability: "high",
-                base: 90,
-            },
-            ArchProfile {
-                name: "FinOps Dashboards",
-                volume: "any",
-                workload: "any",
-                sla: "any",
-                budget: "low",
-                observability: "high",
-                base: 78,
-            },
-            ArchProfile {
-                name: "Hadoop/Spark",
-                volume: "large",
-                workload: "batch",
-                sla: "low",
-                budget: "low",
-                observability: "low",
-                base: 60,
-            },
-            ArchProfile {
-                name: "Data Warehouse",
-                volume: "medium",
-                workload: "batch",
-                sla: "high",
-                budget: "any",
-                observability: "medium",
-                base: 70,
-            },
-            ArchProfile {
-                name: "ETL Pipelines",
-                volume: "any",
-                workload: "batch",
-                sla: "low",
-                budget: "low",
-                observability: "low",
-                base: 50,
-            },
-        ];
+    Scenario {
+        volume,
+        workload,
+        sla,
+        budget,
+        observability,
+        volume_tb,
+        budget_monthly,
+        compliance,
+        residency,
+        latency_ms,
+        availability,
+        team_size,
+        skills,
+        ops_maturity,
+        access_patterns,
+        sources,
+        ml_needs,
+        data_quality,
+        schema_volatility,
+        updates,
+        security,
+        memberships: BTreeMap::new(),
+    }
+}
 
-        let user = (
-            volume.as_str(),
-            workload.as_str(),
-            sla.as_str(),
-            budget.as_str(),
-            observability.as_str(),
-        );
-        let weights = (sla_w, budget_w, volume_w, workload_w, observability_w);
+/// Asks only the core questions that can still change the top recommendation,
+/// most informative first; optional inputs are left empty.
+fn read_adaptive(prices: &PriceTable) -> (Scenario, Weights) {
+    let mut planner = questionnaire::Planner::new(|scenario: &Scenario, weights: &Weights| {
+        evaluate_multi(scenario, weights).first().map(|r| r.0)
+    });
+    let (mut volume_tb, mut budget_monthly) = (None, None);
+    let defaults = Weights::default().as_array();
+    while let Some(question) = planner.next_question() {
+        println!("(still in play: {})", planner.candidates().join(", "));
+        match question {
+            questionnaire::Question::Dimension(i) => {
+                let value = match i {
+                    0 => read_input("Enter SLA requirement (low/high):"),
+                    1 => {
+                        let (budget, monthly) = parse_budget(
+                            &read_input("Enter budget (low/high or monthly amount, e.g. 15000):"),
+                            prices.high_budget_threshold,
+                        );
+                        budget_monthly = monthly;
+                        budget
+                    }
+                    2 => {
+                        let (volume, tb) = parse_volume(&read_input(
+                            "Enter data volume (small/medium/large or size in TB, e.g. 40tb):",
+                        ));
+                        volume_tb = tb;
+                        volume
+                    }
+                    3 => read_input("Enter workload type (batch/streaming/mixed):"),
+                    _ => read_input("Enter observability requirement (low/medium/high):"),
+                };
+                planner.answer_value(i, value);
+            }
+            questionnaire::Question::Weight(i) => {
+                let prompt = format!("Enter {} weight", ahp::CRITERIA[i]);
+                planner.answer_weight(i, read_weight(&prompt, defaults[i]));
+            }
+        }
+    }
+    questionnaire::print_skipped(&planner);
 
-        let mut scored: Vec<_> = profiles
-            .iter()
-            .map(|p| (p.name, similarity_score(user, weights, p)))
-            .collect();
+    let [sla, budget, volume, workload, observability] = planner.values();
+    let scenario = Scenario {
+        volume,
+        workload,
+        sla,
+        budget,
+        observability,
+        volume_tb,
+        budget_monthly,
+        ..Scenario::default()
+    };
+    (scenario, planner.weights())
+}
 
-        scored.sort_by(|a, b| b.1.cmp(&a.1));
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if let Some(flag) = missing_value(&args, VALUE_FLAGS) {
+        eprintln!("{} needs a value", flag);
+        std::process::exit(1);
+    }
+    // Fuzzy inference and the adaptive questionnaire rank the whole catalog
+    // like multi mode.
+    let fuzzy_mode = has_flag(&args, "--fuzzy");
+    let adaptive = has_flag(&args, "--adaptive");
+    let multi = has_flag(&args, "--multi") || fuzzy_mode || adaptive;
+    let growth = has_flag(&args, "--growth");
+    let pairwise = has_flag(&args, "--ahp");
+    let show_pareto = has_flag(&args, "--pareto");
 
-        println!("\n=== Recommendations ===");
-        for (name, score) in scored {
-            println!("-> {} (score: {})", name, score);
-        }
+    let prices = match flag_value(&args, "--prices") {
+        Some(path) => PriceTable::load(path).unwrap_or_else(|e| {
+            eprintln!("Cannot load price table {}", e);
+            std::process::exit(1);
+        }),
+        None => PriceTable::default(),
+    };
+
+    // Skipping questions relies on additive scores being linear in the weights.
+    if adaptive && (fuzzy_mode || has_flag(&args, "--ranking")) {
+        eprintln!("--adaptive plans its questions with the additive ranking; it cannot be combined with --ranking or --fuzzy");
+        std::process::exit(1);
+    }
+    if fuzzy_mode && has_flag(&args, "--ranking") {
+        eprintln!("--fuzzy ranks by fuzzy inference; it cannot be combined with --ranking");
+        std::process::exit(1);
+    }
+    let tnorm = match flag_value(&args, "--tnorm") {
+        Some(tnorm) => fuzzy::TNorm::parse(tnorm).unwrap_or_else(|| {
+            eprintln!("Unknown t-norm {} (min/product)", tnorm);
+            std::process::exit(1);
+        }),
+        None => fuzzy::TNorm::Min,
+    };
+
+    println!("=== Big Data Architecture Advisor CLI ===");
+    if fuzzy_mode {
+        println!("*** FUZZY Inference Mode ***\n");
+    } else if multi {
+        println!("*** MULTI Recommendations Mode ***\n");
     } else {
-        let mut runtime = Crepe::new();
+        println!("*** STRICT Rule-Based Mode (using Crepe library) ***\n");
+    }
 
-        runtime.extend(&[Volume(Box::leak(volume.clone().into_boxed_str()))]);
-        runtime.extend(&[Workload(Box::leak(workload.clone().into_boxed_str()))]);
-        runtime.extend(&[SLA(Box::leak(sla.clone().into_boxed_str()))]);
-        runtime.extend(&[Budget(Box::leak(budget.clone().into_boxed_str()))]);
-        runtime.extend(&[Observability(Box::leak(
-            observability.clone().into_boxed_str(),
-        ))]);
+    let strategy: Box<dyn RankingStrategy> = match flag_value(&args, "--ranking") {
+        Some(method) => ranking::parse(method).unwrap_or_else(|| {
+            eprintln!(
+                "Unknown ranking method {} (additive/product/topsis/electre)",
+                method
+            );
+            std::process::exit(1);
+        }),
+        None if fuzzy_mode => Box::new(fuzzy::Fuzzy {
+            tnorm,
+            budget_threshold: prices.high_budget_threshold,
+        }),
+        None => Box::new(ranking::Additive),
+    };
+    if multi {
+        println!("Ranking: {}\n", strategy.name());
+    }
 
-        let (recs, expls) = runtime.run();
+    if has_flag(&args, "--tui") {
+        let (scenario, weights) = match flag_value(&args, "--scenario") {
+            Some(path) => {
+                let file = ScenarioFile::load(path).unwrap_or_else(|e| {
+                    eprintln!("Cannot load scenario {}", e);
+                    std::process::exit(1);
+                });
+                (file.scenario, file.weights.unwrap_or_default())
+            }
+            None => (tui::starting_scenario(), Weights::default()),
+        };
+        if let Err(e) = tui::run(&mut tui::App::new(scenario, weights, strategy.as_ref())) {
+            eprintln!("Terminal error {}", e);
+        }
+        return;
+    }
 
-        let mut recs_adjusted: Vec<Recommendation> = recs
-            .into_iter()
-            .map(|Recommendation(r, base)| {
-                let weight_sum = sla_w + budget_w + volume_w + workload_w + observability_w;
-                let score = base * weight_sum / 10;
-                Recommendation(r, score)
-            })
-            .collect();
+    let aggregation = match flag_value(&args, "--aggregate") {
+        Some(method) => Aggregation::parse(method).unwrap_or_else(|| {
+            eprintln!(
+                "Unknown aggregation method {} (mean/median/geomean/minimax)",
+                method
+            );
+            std::process::exit(1);
+        }),
+        None => Aggregation::Mean,
+    };
 
-        recs_adjusted.sort_by(|a, b| b.1.cmp(&a.1));
+    if args.get(1).map(String::as_str) == Some("compare") {
+        // Scenarios come right after the command, flags after them.
+        let mut scenarios: Vec<compare::Compared> = vec![];
+        for arg in args[2..].iter().take_while(|a| !a.starts_with("--")) {
+            match compare::parse(arg, scenarios.first(), &prices, aggregation) {
+                Ok(c) => scenarios.push(c),
+                Err(e) => {
+                    eprintln!("Cannot read scenario {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        if scenarios.len() < 2 {
+            eprintln!("compare needs at least two scenarios (files or input=value lists)");
+            std::process::exit(1);
+        }
+        let comparison = compare::Comparison {
+            multi,
+            strategy: strategy.as_ref(),
+        };
+        compare::print(&comparison, &scenarios);
+        return;
+    }
+
+    let (scenario, file_weights, stakeholders) = match flag_value(&args, "--scenario") {
+        Some(path) => {
+            let file = ScenarioFile::load(path).unwrap_or_else(|e| {
+                eprintln!("Cannot load scenario {}", e);
+                std::process::exit(1);
+            });
+            (file.scenario, file.weights, file.stakeholders)
+        }
+        None if adaptive => {
+            let (scenario, weights) = read_adaptive(&prices);
+            (scenario, Some(weights), vec![])
+        }
+        None => {
+            let mut scenario = read_scenario(&prices);
+            if fuzzy_mode {
+                scenario.memberships = read_memberships();
+            }
+            (scenario, None, read_stakeholders(pairwise))
+        }
+    };
+    if fuzzy_mode {
+        fuzzy::print_memberships(&fuzzy::memberships(&scenario, prices.high_budget_threshold));
+    }
 
-        println!("\n=== Recommendations ===");
-        if let Some(Recommendation(r, score)) = recs_adjusted.first() {
-            println!("-> {} (score: {})", r, score);
+    let weights = if !stakeholders.is_empty() {
+        group::aggregate_weights(&stakeholders, aggregation)
+    } else {
+        file_weights.unwrap_or_else(|| read_weights("", pairwise))
+    };
+
+    if has_flag(&args, "--repl") {
+        repl::run(&mut repl::Session::new(
+            scenario,
+            weights,
+            multi,
+            strategy.as_ref(),
+            &prices,
+        ));
+        return;
+    }
+
+    let mut ranked = rank(&scenario, &weights, multi, strategy.as_ref());
+    if !stakeholders.is_empty() {
+        let rankings: Vec<_> = stakeholders
+            .iter()
+            .map(|s| rank(&scenario, &s.weights, multi, strategy.as_ref()))
+            .collect();
+        if aggregation == Aggregation::MinimaxRegret {
+            ranked = group::minimax_regret(&rankings);
         }
+        group::print_rankings(&stakeholders, &rankings, &ranked, aggregation, MULTI_TOP);
+    }
+    // Rule explanations in ranking order; the HTML page shows them in both modes.
+    let strict_explanations: Vec<String> = evaluate_strict(&scenario, &weights)
+        .1
+        .iter()
+        .map(|Explanation(_, e)| e.to_string())
+        .collect();
+    let explanations = if multi {
+        vec![]
+    } else {
+        strict_explanations.clone()
+    };
 
-        println!("\n=== Explanations ===");
-        for Explanation(e) in expls {
-            println!("- {}", e);
+    let chosen = if multi {
+        &ranked[..ranked.len().min(MULTI_TOP)]
+    } else {
+        &ranked[..]
+    };
+    let warnings = risk_warnings(&scenario, chosen)
+        .into_iter()
+        .map(|Warning(message, severity, mitigation)| RiskWarning {
+            message: message.to_string(),
+            severity: severity.to_string(),
+            mitigation: mitigation.to_string(),
+        })
+        .collect();
+    let report =
+        Report::new(&ranked, explanations, &scenario, &weights, &prices).with_warnings(warnings);
+    report.print_recommendations(multi);
+    if !multi {
+        report.print_explanations();
+    }
+    let platforms = storage_picks(chosen);
+    formats::print(&data_formats(&scenario, &platforms));
+    security::print(&security_controls(&scenario, &platforms));
+    report.print_warnings();
+    if show_pareto {
+        let candidates: Vec<ArchProfile> = profiles_for(&scenario)
+            .into_iter()
+            .filter(|p| compliance::satisfies_residency(p.name, &scenario))
+            .collect();
+        pareto::print(&pareto::pareto_front(&candidates, &scenario, &weights));
+    }
+    if let Some(path) = flag_value(&args, "--json") {
+        match report.write_json(path) {
+            Ok(()) => println!("\nJSON report written to {}", path),
+            Err(e) => eprintln!("Cannot write JSON report {}", e),
+        }
+    }
+    if has_flag(&args, "--mermaid") || has_flag(&args, "--graphviz") {
+        let names: Vec<&str> = chosen.iter().map(|(name, _)| *name).collect();
+        let diagram = diagram::Diagram::build(&scenario, &names);
+        for (flag, render) in [
+            (
+                "--mermaid",
+                diagram::Diagram::mermaid as fn(&diagram::Diagram) -> String,
+            ),
+            ("--graphviz", diagram::Diagram::graphviz),
+        ] {
+            if let Some(path) = flag_value(&args, flag) {
+                match std::fs::write(path, render(&diagram)) {
+                    Ok(()) => println!("\nDiagram written to {}", path),
+                    Err(e) => eprintln!("Cannot write diagram {}: {}", path, e),
+                }
+            }
+        }
+    }
+    let method = if multi {
+        strategy.name()
+    } else {
+        "strict rules"
+    };
+    if let Some(dir) = flag_value(&args, "--adr") {
+        match adr::write(dir, &report, &scenario, &weights, method) {
+            Ok(path) => println!("\nADR written to {}", path.display()),
+            Err(e) => eprintln!("Cannot write ADR {}", e),
         }
     }
+    if let Some(path) = flag_value(&args, "--html") {
+        match html::write(
+            path,
+            &report,
+            &scenario,
+            &weights,
+            &strict_explanations,
+            method,
+            multi && strategy.is_additive(),
+        ) {
+            Ok(()) => println!("\nHTML report written to {}", path),
+            Err(e) => eprintln!("Cannot write HTML report {}", e),
+        }
+    }
+    compliance::print_exclusions(&scenario);
+    for posture in security::unknown_postures(&scenario) {
+        println!("! Unknown security posture ignored: {}", posture);
+    }
+    ml::print_training_estimates(&scenario, &prices);
+
+    if let Some(current) = flag_value(&args, "--current") {
+        let (current, unknown) = migration::parse_current(current);
+        let mut plan = migration::plan(&current, chosen, &prices);
+        plan.unknown = unknown;
+        plan.print();
+    }
+
+    if growth {
+        let growth_pct =
+            growth::validate_growth(read_number("Enter yearly data growth rate in %", 30.0))
+                .unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                });
+        let horizon = growth::validate_horizon(read_number(
+            &format!(
+                "Enter projection horizon in years (1-{})",
+                growth::MAX_HORIZON_YEARS
+            ),
+            3.0,
+        ))
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        });
+        let milestones =
+            growth::project(&scenario, &weights, strategy.as_ref(), growth_pct, horizon);
+        growth::print_roadmap(&milestones, growth_pct);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[allow(clippy::too_many_arguments, clippy::unnecessary_sort_by)]
     fn run_strict(
         volume: &str,
         workload: &str,
@@ -441,7 +1383,7 @@ mod tests {
             observability.to_string().into_boxed_str(),
         ))]);
 
-        let (recs, _expls) = runtime.run();
+        let (recs, _expls, _formats, _controls, _warnings) = runtime.run();
         let mut recs_adjusted: Vec<Recommendation> = recs
             .into_iter()
             .map(|Recommendation(r, base)| {
@@ -454,6 +1396,11 @@ mod tests {
         recs_adjusted
     }
 
+    #[allow(
+        clippy::too_many_arguments,
+        clippy::useless_vec,
+        clippy::unnecessary_sort_by
+    )]
     fn run_multi(
         volume: &str,
         workload: &str,
@@ -570,6 +1517,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::identity_op)]
     fn test_exact_match_full_points() {
         let profile = make_profile("Lakehouse", "large", "mixed", "high", "high", "high", 95);
         let user = ("large", "mixed", "high", "high", "high");
@@ -581,6 +1529,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::identity_op)]
     fn test_partial_match_any_fields() {
         let profile = make_profile("Kafka/Flink", "any", "streaming", "high", "any", "high", 90);
         let user = ("small", "streaming", "high", "low", "high");
@@ -602,6 +1551,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::identity_op)]
     fn test_observability_partial_credit() {
         let profile = make_profile(
             "Cloud-native Monitoring",
@@ -628,4 +1578,361 @@ mod tests {
         // Observability exact match with high weight should dominate
         assert!(score > 150);
     }
+
+    #[test]
+    fn test_strict_mode_compliance_rules() {
+        let scenario = Scenario {
+            volume: "medium".to_string(),
+            workload: "batch".to_string(),
+            sla: "low".to_string(),
+            budget: "low".to_string(),
+            observability: "low".to_string(),
+            compliance: vec!["pci".to_string()],
+            residency: Some("cn".to_string()),
+            ..Scenario::default()
+        };
+        let (recs, expls) = evaluate_strict(&scenario, &Weights::default());
+        let names: Vec<_> = recs.iter().map(|r| r.0).collect();
+        assert!(names.contains(&"Encryption & Key Management (KMS/HSM)"));
+        assert!(names.contains(&"Audit Logging (CloudTrail/Audit Vault)"));
+        // Masking SaaS and the governance catalog are not available in China
+        assert!(!names.contains(&"Data Masking & Tokenization (Immuta/Privacera)"));
+        assert!(!names.contains(&"Governance Layer (Collibra/Alation)"));
+        assert!(expls.iter().all(|e| names.contains(&e.0)));
+    }
+
+    #[test]
+    fn test_unknown_regimes_and_regions_add_no_compliance_rules() {
+        let scenario = Scenario {
+            observability: "low".to_string(),
+            compliance: vec!["sox".to_string()],
+            residency: Some("de".to_string()),
+            ..Scenario::default()
+        };
+        let (recs, _) = evaluate_strict(&scenario, &Weights::default());
+        assert!(!recs
+            .iter()
+            .any(|r| r.0 == "Governance Layer (Collibra/Alation)"
+                || r.0 == "Audit Logging (CloudTrail/Audit Vault)"));
+        assert!(risk_warnings(&scenario, &[]).is_empty());
+        assert!(!profiles_for(&scenario)
+            .iter()
+            .any(|p| p.name == "Audit Logging (CloudTrail/Audit Vault)"));
+    }
+
+    #[test]
+    fn test_strict_mode_prefers_managed_spark_for_small_team() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "batch".to_string(),
+            sla: "low".to_string(),
+            budget: "low".to_string(),
+            observability: "low".to_string(),
+            team_size: Some(3),
+            skills: vec!["python".to_string(), "sql".to_string()],
+            ops_maturity: Some("low".to_string()),
+            ..Scenario::default()
+        };
+        let (recs, _expls) = evaluate_strict(&scenario, &Weights::default());
+        assert_eq!(recs[0].0, "Managed Spark (Databricks/EMR)");
+        let hadoop = recs.iter().find(|r| r.0 == "Hadoop/Spark").unwrap();
+        assert_eq!(hadoop.1, 60 * 15 / 10 - 45 - 10);
+    }
+
+    #[test]
+    fn test_strict_mode_ingestion_rules() {
+        let scenario = Scenario {
+            volume: "medium".to_string(),
+            workload: "streaming".to_string(),
+            sla: "low".to_string(),
+            budget: "low".to_string(),
+            observability: "low".to_string(),
+            sources: vec!["oltp".to_string(), "saas".to_string()],
+            ..Scenario::default()
+        };
+        let (recs, expls) = evaluate_strict(&scenario, &Weights::default());
+        let names: Vec<_> = recs.iter().map(|r| r.0).collect();
+        assert_eq!(names[0], "Change Data Capture (Debezium)");
+        assert!(names.contains(&"Kafka Connect"));
+        assert!(names.contains(&"Managed ELT (Fivetran/Airbyte)"));
+        assert!(!names.contains(&"MQTT Bridge (EMQX/HiveMQ)"));
+        assert!(expls.iter().any(|e| e.1.starts_with("CDC chosen")));
+    }
+
+    #[test]
+    fn test_strict_mode_ml_rules() {
+        let scenario = Scenario {
+            volume: "medium".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "low".to_string(),
+            observability: "low".to_string(),
+            latency_ms: Some(50.0),
+            ml_needs: vec![
+                "deep-learning".to_string(),
+                "inference".to_string(),
+                "retrieval".to_string(),
+            ],
+            ..Scenario::default()
+        };
+        let (recs, expls) = evaluate_strict(&scenario, &Weights::default());
+        let names: Vec<_> = recs.iter().map(|r| r.0).collect();
+        assert!(names.contains(&"Model Serving (KServe/Seldon)"));
+        assert!(names.contains(&"Vector Database (Milvus/pgvector/Pinecone)"));
+        assert!(names.contains(&"Managed ML Platform (SageMaker/Vertex AI)"));
+        assert!(!names.contains(&"GPU Training Cluster (Ray/KubeRay)"));
+        assert!(expls.iter().any(|e| e.1.contains("rent GPUs")));
+    }
+
+    #[test]
+    fn test_flag_value_skips_other_flags() {
+        let args: Vec<String> = ["advisor", "--mermaid", "--json", "out.json", "--graphviz"]
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        assert_eq!(flag_value(&args, "--mermaid"), None);
+        assert_eq!(flag_value(&args, "--json"), Some("out.json"));
+        assert_eq!(flag_value(&args, "--graphviz"), None);
+        assert_eq!(missing_value(&args, VALUE_FLAGS), Some("--mermaid"));
+        assert_eq!(missing_value(&args[..4], &["--json", "--html"]), None);
+    }
+
+    #[test]
+    fn test_online_features_rank_key_value_store_in_both_modes() {
+        let scenario = Scenario {
+            ml_needs: vec!["features".to_string(), "inference".to_string()],
+            ..Scenario::default()
+        };
+        let (recs, _) = evaluate_strict(&scenario, &Weights::default());
+        let strict = recs
+            .iter()
+            .find(|r| r.0 == "Key-Value Store (Redis/Cassandra/DynamoDB)")
+            .unwrap();
+        let profile = profiles_for(&scenario)
+            .into_iter()
+            .find(|p| p.name == "Key-Value Store (Redis/Cassandra/DynamoDB)")
+            .unwrap();
+        assert_eq!(strict.1, profile.base * Weights::default().sum() / 10);
+    }
+
+    #[test]
+    fn test_strict_mode_keeps_strongest_rule_per_architecture() {
+        // Two rules recommend Model Serving: one for inference, one when the
+        // latency target is real-time as well. It is listed once.
+        let scenario = Scenario {
+            volume: "medium".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "low".to_string(),
+            observability: "low".to_string(),
+            latency_ms: Some(50.0),
+            ml_needs: vec!["inference".to_string(), "features".to_string()],
+            ..Scenario::default()
+        };
+        let weights = Weights::default();
+        let (recs, _) = evaluate_strict(&scenario, &weights);
+        let serving: Vec<_> = recs
+            .iter()
+            .filter(|r| r.0 == "Model Serving (KServe/Seldon)")
+            .collect();
+        assert_eq!(serving.len(), 1);
+        assert_eq!(
+            serving[0].1,
+            86 * weights.sum() / 10
+                + adjustments::total(&scenario, &weights, "Model Serving (KServe/Seldon)")
+        );
+        let mut names: Vec<_> = recs.iter().map(|r| r.0).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), recs.len());
+    }
+
+    #[test]
+    fn test_high_observability_includes_data_quality() {
+        let scenario = Scenario {
+            volume: "medium".to_string(),
+            workload: "streaming".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "high".to_string(),
+            ..Scenario::default()
+        };
+        let (recs, expls) = evaluate_strict(&scenario, &Weights::default());
+        let names: Vec<_> = recs.iter().map(|r| r.0).collect();
+        assert!(names.contains(&"Data Quality Monitoring (Soda)"));
+        assert!(names.contains(&"Schema Registry (Confluent/Apicurio)"));
+        assert!(!names.contains(&"dbt Tests"));
+        assert!(expls
+            .iter()
+            .any(|e| e.0 == "Data Quality Monitoring (Soda)"));
+
+        let multi = evaluate_multi(&scenario, &Weights::default());
+        assert!(multi
+            .iter()
+            .any(|(n, _)| *n == "Schema Registry (Confluent/Apicurio)"));
+        let relaxed = Scenario {
+            data_quality: Some("low".to_string()),
+            ..scenario
+        };
+        let multi = evaluate_multi(&relaxed, &Weights::default());
+        assert!(!multi
+            .iter()
+            .any(|(n, _)| *n == "Schema Registry (Confluent/Apicurio)"));
+    }
+
+    #[test]
+    fn test_formats_follow_storage_recommendation() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "low".to_string(),
+            updates: Some("frequent".to_string()),
+            schema_volatility: Some("high".to_string()),
+            ..Scenario::default()
+        };
+        let formats = data_formats(&scenario, &["Lakehouse"]);
+        assert!(formats
+            .iter()
+            .any(|f| f.0 == "Apache Hudi/Delta Lake (merge-on-read)" && f.1 == "Lakehouse"));
+        assert!(formats
+            .iter()
+            .any(|f| f.0 == "Apache Iceberg" && f.1 == "Lakehouse"));
+        assert!(!formats.iter().any(|f| f.0 == "Parquet/ORC"));
+        // Other storage the rules fire for gets no formats unless picked.
+        assert!(formats.iter().all(|f| f.1 == "Lakehouse"));
+        assert!(data_formats(&scenario, &[]).is_empty());
+
+        let streaming = Scenario {
+            workload: "streaming".to_string(),
+            updates: None,
+            schema_volatility: None,
+            ..scenario
+        };
+        let formats = data_formats(&streaming, &["Kafka/Flink"]);
+        assert!(formats
+            .iter()
+            .any(|f| f.0 == "Protobuf + Schema Registry" && f.1 == "Kafka/Flink"));
+    }
+
+    #[test]
+    fn test_formats_follow_storage_below_top_pick() {
+        let scenario = Scenario {
+            volume: "medium".to_string(),
+            workload: "batch".to_string(),
+            sla: "high".to_string(),
+            budget: "low".to_string(),
+            observability: "high".to_string(),
+            schema_volatility: Some("high".to_string()),
+            security: vec!["pii".to_string(), "zero-trust".to_string()],
+            ..Scenario::default()
+        };
+        let weights = Weights::from_array([5, 3, 2, 1, 4]);
+        let (recs, _) = evaluate_strict(&scenario, &weights);
+        let chosen: Vec<_> = recs.iter().map(|Recommendation(r, s)| (*r, *s)).collect();
+        assert!(
+            !formats::is_warehouse(chosen[0].0) && !formats::is_open_format_storage(chosen[0].0)
+        );
+        assert_eq!(storage_picks(&chosen), vec!["Data Warehouse"]);
+        let formats = data_formats(&scenario, &storage_picks(&chosen));
+        assert_eq!(formats.len(), 1);
+        assert_eq!(formats[0].0, "Apache Iceberg (external tables)");
+        let controls = security_controls(&scenario, &storage_picks(&chosen));
+        assert!(controls
+            .iter()
+            .any(|c| c.0 == "Network isolation" && c.1 == "Data Warehouse"));
+    }
+
+    #[test]
+    fn test_pii_and_zero_trust_explained_separately() {
+        let scenario = Scenario {
+            security: vec!["pii".to_string()],
+            ..Scenario::default()
+        };
+        let (_, expls) = evaluate_strict(&scenario, &Weights::default());
+        let keys: Vec<_> = expls
+            .iter()
+            .filter(|e| e.0 == "Encryption & Key Management (KMS/HSM)")
+            .collect();
+        assert_eq!(keys.len(), 1);
+        assert!(keys[0].1.contains("PII") && !keys[0].1.contains("zero-trust"));
+    }
+
+    #[test]
+    fn test_security_controls_linked_to_platform() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "low".to_string(),
+            security: vec!["pii".to_string(), "zero-trust".to_string()],
+            ..Scenario::default()
+        };
+        let (recs, _) = evaluate_strict(&scenario, &Weights::default());
+        let names: Vec<_> = recs.iter().map(|r| r.0).collect();
+        assert!(names.contains(&"Network Isolation (VPC/Private Link)"));
+        assert!(names.contains(&"Encryption & Key Management (KMS/HSM)"));
+        assert!(!names.contains(&"Access Control (Apache Ranger/Lake Formation)"));
+
+        let controls = security_controls(&scenario, &["Lakehouse"]);
+        for control in [
+            "Column/row-level security",
+            "Key management",
+            "Network isolation",
+        ] {
+            assert!(controls
+                .iter()
+                .any(|c| c.0 == control && c.1 == "Lakehouse"));
+        }
+        assert!(!controls
+            .iter()
+            .any(|c| c.0 == "Fine-grained access control"));
+        assert!(controls.iter().all(|c| c.1 == "Lakehouse"));
+        assert!(security_controls(&scenario, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_posture_adds_no_security_rules() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "low".to_string(),
+            security: vec!["airgap".to_string()],
+            ..Scenario::default()
+        };
+        let (_, expls) = evaluate_strict(&scenario, &Weights::default());
+        assert!(!expls
+            .iter()
+            .any(|e| e.0 == "Encryption & Key Management (KMS/HSM)"));
+        assert!(security_controls(&scenario, &["Lakehouse"]).is_empty());
+    }
+
+    #[test]
+    fn test_warnings_for_risky_picks() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "streaming".to_string(),
+            sla: "high".to_string(),
+            budget: "low".to_string(),
+            observability: "low".to_string(),
+            team_size: Some(3),
+            compliance: vec!["gdpr".to_string()],
+            ..Scenario::default()
+        };
+        let warnings = risk_warnings(&scenario, &[("Hadoop/Spark", 100), ("Data Mesh", 90)]);
+        let messages: Vec<_> = warnings.iter().map(|w| w.0).collect();
+        assert!(
+            messages.contains(&"Hadoop/Spark with high SLA and streaming workload: latency risk")
+        );
+        assert!(messages.contains(&"Data Mesh with a small team: organizational overhead"));
+        assert!(messages.contains(&"Regulated data with low observability: audit gaps"));
+        assert!(warnings.iter().all(|w| w.1 == "high"));
+
+        let warnings = risk_warnings(&scenario, &[("Kafka/Flink", 100)]);
+        assert_eq!(warnings.len(), 1);
+    }
 }
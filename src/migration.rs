@@ -0,0 +1,227 @@
+use crate::catalog::{layer_of, resolve_name, Layer};
+use crate::cost::PriceTable;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Effort {
+    Small,
+    Medium,
+    Large,
+    ExtraLarge,
+}
+
+impl Effort {
+    /// Effort class derived from the one-off setup cost of the target architecture.
+    pub fn from_setup_cost(setup: f64) -> Self {
+        if setup < 10000.0 {
+            Effort::Small
+        } else if setup < 30000.0 {
+            Effort::Medium
+        } else if setup < 80000.0 {
+            Effort::Large
+        } else {
+            Effort::ExtraLarge
+        }
+    }
+
+    /// Replacing a running component also means moving its data and users.
+    pub fn bump(self) -> Self {
+        match self {
+            Effort::Small => Effort::Medium,
+            Effort::Medium => Effort::Large,
+            _ => Effort::ExtraLarge,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Effort::Small => "S",
+            Effort::Medium => "M",
+            Effort::Large => "L",
+            Effort::ExtraLarge => "XL",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Add,
+    Replace(&'static str),
+}
+
+pub struct Step {
+    pub number: usize,
+    pub action: Action,
+    pub target: &'static str,
+    pub layer: Layer,
+    pub effort: Effort,
+    /// Numbers of the steps that must be finished first.
+    pub after: Vec<usize>,
+}
+
+#[derive(Default)]
+pub struct MigrationPlan {
+    pub keep: Vec<&'static str>,
+    pub steps: Vec<Step>,
+    pub unknown: Vec<String>,
+}
+
+/// Parses a comma-separated list of catalog names, returning the resolved
+/// names and the entries that did not match any catalog architecture.
+pub fn parse_current(input: &str) -> (Vec<&'static str>, Vec<String>) {
+    let mut known = Vec::new();
+    let mut unknown = Vec::new();
+    for item in input.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        match resolve_name(item) {
+            Some(name) if !known.contains(&name) => known.push(name),
+            Some(_) => {}
+            None => unknown.push(item.to_string()),
+        }
+    }
+    (known, unknown)
+}
+
+/// The best-ranked architecture of every exclusive layer and all ranked
+/// architectures of the complementary layers, in layer order.
+fn targets(ranked: &[(&'static str, i32)]) -> Vec<(Layer, &'static str)> {
+    let mut result: Vec<(Layer, &'static str)> = Vec::new();
+    for (name, _) in ranked {
+        if let Some(layer) = layer_of(name) {
+            if !layer.is_exclusive() || !result.iter().any(|(l, _)| *l == layer) {
+                result.push((layer, name));
+            }
+        }
+    }
+    result.sort_by_key(|(layer, _)| *layer);
+    result
+}
+
+/// Computes which current components to keep, replace or add to reach the
+/// recommended architecture. Steps are ordered by layer so that storage
+/// lands before the processing and orchestration built on top of it.
+pub fn plan(
+    current: &[&'static str],
+    ranked: &[(&'static str, i32)],
+    prices: &PriceTable,
+) -> MigrationPlan {
+    let mut plan = MigrationPlan::default();
+
+    for (layer, target) in targets(ranked) {
+        if current.contains(&target) {
+            plan.keep.push(target);
+            continue;
+        }
+
+        let effort =
+            Effort::from_setup_cost(prices.models.get(target).map(|m| m.setup).unwrap_or(0.0));
+        let replaced = current
+            .iter()
+            .filter(|_| layer.is_exclusive())
+            .find(|c| layer_of(c) == Some(layer) && !plan.replaces(c));
+        let (action, effort) = match replaced {
+            Some(from) => (Action::Replace(from), effort.bump()),
+            None => (Action::Add, effort),
+        };
+        let after = plan
+            .steps
+            .iter()
+            .filter(|s| layer.depends_on().contains(&s.layer))
+            .map(|s| s.number)
+            .collect();
+        plan.steps.push(Step {
+            number: plan.steps.len() + 1,
+            action,
+            target,
+            layer,
+            effort,
+            after,
+        });
+    }
+
+    // Components in layers the recommendation does not touch stay as they are.
+    for name in current {
+        if !plan.keep.contains(name) && !plan.replaces(name) {
+            plan.keep.push(name);
+        }
+    }
+    plan
+}
+
+impl MigrationPlan {
+    fn replaces(&self, name: &str) -> bool {
+        self.steps
+            .iter()
+            .any(|s| matches!(s.action, Action::Replace(from) if from == name))
+    }
+
+    pub fn print(&self) {
+        println!("\n=== Migration Plan ===");
+        for name in &self.unknown {
+            println!("! Unknown architecture ignored: {}", name);
+        }
+        for name in &self.keep {
+            println!("= keep {}", name);
+        }
+        if self.steps.is_empty() {
+            println!("- Current stack already matches the recommendation.");
+        }
+        for step in &self.steps {
+            let what = match step.action {
+                Action::Add => format!("add {}", step.target),
+                Action::Replace(from) => format!("replace {} with {}", from, step.target),
+            };
+            let after = if step.after.is_empty() {
+                String::new()
+            } else {
+                let deps: Vec<String> = step.after.iter().map(|n| n.to_string()).collect();
+                format!(", after step {}", deps.join(", "))
+            };
+            println!(
+                "{}. {} [{}] (effort: {}{})",
+                step.number,
+                what,
+                step.layer.label(),
+                step.effort.label(),
+                after
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_current_resolves_short_names() {
+        let (known, unknown) = parse_current("hadoop/spark, ELK Stack, Teradata");
+        assert_eq!(
+            known,
+            vec!["Hadoop/Spark", "ELK Stack (Elasticsearch/Logstash/Kibana)"]
+        );
+        assert_eq!(unknown, vec!["Teradata".to_string()]);
+    }
+
+    #[test]
+    fn test_plan_keeps_replaces_and_adds() {
+        let current = ["Data Warehouse", "Hadoop/Spark", "FinOps Dashboards"];
+        // Hadoop/Spark stays: no processing architecture is recommended.
+        let ranked = [
+            ("Lakehouse", 95),
+            ("Data Warehouse", 70),
+            ("ML Platform (Kubeflow/MLflow)", 88),
+            ("Orchestration (Airflow/Prefect)", 65),
+            ("FinOps Dashboards", 60),
+        ];
+        let plan = plan(&current, &ranked, &PriceTable::default());
+
+        assert_eq!(plan.keep, vec!["FinOps Dashboards", "Hadoop/Spark"]);
+        assert_eq!(plan.steps.len(), 3);
+        assert_eq!(plan.steps[0].target, "Lakehouse");
+        assert_eq!(plan.steps[0].action, Action::Replace("Data Warehouse"));
+        assert_eq!(plan.steps[1].target, "ML Platform (Kubeflow/MLflow)");
+        assert_eq!(plan.steps[1].action, Action::Add);
+        assert_eq!(plan.steps[1].after, vec![1]);
+        assert_eq!(plan.steps[2].target, "Orchestration (Airflow/Prefect)");
+        assert_eq!(plan.steps[2].after, Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::cost::{format_money, PriceTable};
+use crate::scenario::Scenario;
+
+pub const ML_NEEDS: &[&str] = &[
+    "training",
+    "deep-learning",
+    "inference",
+    "features",
+    "retrieval",
+    "experiments",
+];
+
+// Share of the stored volume used as a training set, and full retrains per month.
+const TRAINING_SHARE: f64 = 0.1;
+const RUNS_PER_MONTH: f64 = 4.0;
+
+/// Training hardware: TB processed per instance-hour, for classical models
+/// and for deep learning. Hourly prices come from the price table.
+pub struct Hardware {
+    pub name: &'static str,
+    pub tb_per_hour: f64,
+    pub dl_tb_per_hour: f64,
+}
+
+pub const HARDWARE: &[Hardware] = &[
+    Hardware {
+        name: "CPU (32 vCPU)",
+        tb_per_hour: 0.2,
+        dl_tb_per_hour: 0.005,
+    },
+    Hardware {
+        name: "GPU (1x A100)",
+        tb_per_hour: 0.4,
+        dl_tb_per_hour: 0.1,
+    },
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrainingEstimate {
+    pub hardware: &'static str,
+    pub hours_per_run: f64,
+    pub monthly: f64,
+}
+
+pub fn unknown_ml_needs(scenario: &Scenario) -> Vec<&str> {
+    scenario
+        .ml_needs
+        .iter()
+        .map(|n| n.as_str())
+        .filter(|n| !ML_NEEDS.contains(n))
+        .collect()
+}
+
+fn is_deep_learning(scenario: &Scenario) -> bool {
+    scenario.ml_needs.iter().any(|n| n == "deep-learning")
+}
+
+/// CPU vs GPU training time and monthly compute cost, cheapest first.
+/// Empty unless training or deep learning is among the ML needs; hardware
+/// without an hourly price in the table is left out.
+pub fn training_estimates(scenario: &Scenario, prices: &PriceTable) -> Vec<TrainingEstimate> {
+    let deep = is_deep_learning(scenario);
+    if !deep && !scenario.ml_needs.iter().any(|n| n == "training") {
+        return vec![];
+    }
+    let training_tb = scenario.effective_volume_tb() * TRAINING_SHARE;
+    let mut result: Vec<_> = HARDWARE
+        .iter()
+        .filter_map(|hw| {
+            let hourly = *prices.training_hourly.get(hw.name)?;
+            let rate = if deep {
+                hw.dl_tb_per_hour
+            } else {
+                hw.tb_per_hour
+            };
+            let hours_per_run = training_tb / rate;
+            Some(TrainingEstimate {
+                hardware: hw.name,
+                hours_per_run,
+                monthly: hours_per_run * RUNS_PER_MONTH * hourly,
+            })
+        })
+        .collect();
+    result.sort_by(|a, b| a.monthly.total_cmp(&b.monthly));
+    result
+}
+
+pub fn print_training_estimates(scenario: &Scenario, prices: &PriceTable) {
+    for need in unknown_ml_needs(scenario) {
+        println!("! Unknown ML need ignored: {}", need);
+    }
+    let estimates = training_estimates(scenario, prices);
+    let Some(best) = estimates.first() else {
+        return;
+    };
+    let training_tb = scenario.effective_volume_tb() * TRAINING_SHARE;
+    println!(
+        "\n=== Training Estimate ({:.1} TB training set, {} runs/month, {}) ===",
+        training_tb,
+        RUNS_PER_MONTH,
+        if is_deep_learning(scenario) {
+            "deep learning"
+        } else {
+            "classical ML"
+        }
+    );
+    for e in &estimates {
+        println!(
+            "- {}: {:.1} h per run, {}/month",
+            e.hardware,
+            e.hours_per_run,
+            format_money(e.monthly, &prices.currency)
+        );
+    }
+    println!("-> Train on {}", best.hardware);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scenario(needs: &[&str]) -> Scenario {
+        Scenario {
+            volume: "medium".to_string(),
+            ml_needs: needs.iter().map(|n| n.to_string()).collect(),
+            ..Scenario::default()
+        }
+    }
+
+    #[test]
+    fn test_cpu_is_cheaper_for_classical_training() {
+        let prices = PriceTable::default();
+        let estimates = training_estimates(&scenario(&["training"]), &prices);
+        assert_eq!(estimates[0].hardware, "CPU (32 vCPU)");
+        assert_eq!(estimates[0].hours_per_run, 10.0);
+        assert!(training_estimates(&scenario(&["inference"]), &prices).is_empty());
+    }
+
+    #[test]
+    fn test_gpu_wins_for_deep_learning() {
+        let mut prices = PriceTable::default();
+        let estimates = training_estimates(&scenario(&["deep-learning", "bogus"]), &prices);
+        assert_eq!(estimates[0].hardware, "GPU (1x A100)");
+        assert_eq!(estimates[0].hours_per_run, 20.0);
+        assert_eq!(estimates[0].monthly, 20.0 * 4.0 * 4.0);
+        // A price table with cheaper GPUs lowers the estimate.
+        prices
+            .training_hourly
+            .insert("GPU (1x A100)".to_string(), 2.0);
+        let estimates = training_estimates(&scenario(&["deep-learning"]), &prices);
+        assert_eq!(estimates[0].monthly, 20.0 * 4.0 * 2.0);
+        assert_eq!(unknown_ml_needs(&scenario(&["bogus"])), vec!["bogus"]);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ahp::CRITERIA;
+use crate::catalog::ArchProfile;
+use crate::ranking::fits;
+use crate::scenario::{Scenario, Weights};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParetoFront {
+    /// Non-dominated profiles with their per-criterion fit.
+    pub front: Vec<(&'static str, [f64; 5])>,
+    /// Dominated profiles and the front members that dominate them.
+    pub dominated: Vec<(&'static str, Vec<&'static str>)>,
+}
+
+/// Whether `a` is at least as good as `b` on every compared criterion and
+/// better on one. Criteria with weight 0 are left out.
+fn dominates(a: &[f64; 5], b: &[f64; 5], compared: &[bool; 5]) -> bool {
+    let criteria = || (0..5).filter(|&j| compared[j]);
+    criteria().all(|j| a[j] >= b[j]) && criteria().any(|j| a[j] > b[j])
+}
+
+pub fn pareto_front(
+    profiles: &[ArchProfile],
+    scenario: &Scenario,
+    weights: &Weights,
+) -> ParetoFront {
+    let compared = weights.as_array().map(|w| w > 0);
+    let rows: Vec<_> = profiles
+        .iter()
+        .map(|p| (p.name, fits(p, scenario)))
+        .collect();
+    let is_dominated = |fit: &[f64; 5]| {
+        rows.iter()
+            .any(|(_, other)| dominates(other, fit, &compared))
+    };
+    let front: Vec<_> = rows
+        .iter()
+        .filter(|(_, f)| !is_dominated(f))
+        .copied()
+        .collect();
+    // Domination is transitive, so every dominated profile is beaten by a
+    // member of the front; only those are listed.
+    let dominated = rows
+        .iter()
+        .filter(|(_, f)| is_dominated(f))
+        .map(|(name, fit)| {
+            let by = front
+                .iter()
+                .filter(|(_, other)| dominates(other, fit, &compared))
+                .map(|(n, _)| *n)
+                .collect();
+            (*name, by)
+        })
+        .collect();
+    ParetoFront { front, dominated }
+}
+
+fn describe(fit: &[f64; 5]) -> String {
+    CRITERIA
+        .iter()
+        .zip(fit)
+        .map(|(c, f)| format!("{} {}", c.to_lowercase(), f))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn print(result: &ParetoFront) {
+    println!("\n=== Pareto Front (fit per criterion: 1 match, 0.5 any, 0 mismatch) ===");
+    for (name, fit) in &result.front {
+        println!("* {} ({})", name, describe(fit));
+    }
+    if result.dominated.is_empty() {
+        return;
+    }
+    println!("Dominated:");
+    for (name, by) in &result.dominated {
+        println!("- {} (dominated by {})", name, by.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &'static str, sla: &'static str, budget: &'static str) -> ArchProfile {
+        ArchProfile {
+            name,
+            volume: "any",
+            workload: "any",
+            sla,
+            budget,
+            observability: "any",
+            base: 80,
+        }
+    }
+
+    #[test]
+    fn test_front_and_dominators() {
+        let scenario = Scenario {
+            sla: "high".to_string(),
+            budget: "low".to_string(),
+            ..Scenario::default()
+        };
+        let catalog = vec![
+            profile("fast", "high", "high"),
+            profile("cheap", "low", "low"),
+            profile("flexible", "any", "any"),
+            profile("worse", "low", "high"),
+        ];
+        let result = pareto_front(&catalog, &scenario, &Weights::default());
+        let front: Vec<_> = result.front.iter().map(|(n, _)| *n).collect();
+        assert_eq!(front, vec!["fast", "cheap", "flexible"]);
+        assert_eq!(
+            result.dominated,
+            vec![("worse", vec!["fast", "cheap", "flexible"])]
+        );
+
+        // Without a budget weight only SLA counts, so "fast" wins alone.
+        let sla_only = Weights::from_array([5, 0, 0, 0, 0]);
+        let result = pareto_front(&catalog, &scenario, &sla_only);
+        assert_eq!(result.front.len(), 1);
+        assert_eq!(result.front[0].0, "fast");
+    }
+}
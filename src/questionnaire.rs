@@ -0,0 +1,326 @@
+use std::collections::BTreeSet;
+
+use crate::ahp::CRITERIA;
+use crate::scenario::{Scenario, Weights};
+
+/// Answers each dimension can take, in `Weights` order.
+pub const VALUES: [&[&str]; 5] = [
+    &["low", "high"],
+    &["low", "high"],
+    &["small", "medium", "large"],
+    &["batch", "streaming", "mixed"],
+    &["low", "medium", "high"],
+];
+
+// Unanswered weights are assumed to lie in this range; 0 ignores a criterion,
+// as the TUI sliders and --pareto do. Additive scores are linear in the
+// weights, so a profile on top at both ends of every weight is on top for
+// every weight in between. That does not hold for the other ranking methods,
+// which is why adaptive mode always ranks additively.
+const WEIGHT_RANGE: [i32; 2] = [0, 10];
+
+/// One question of the core questionnaire; indices are in `Weights` order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Question {
+    Dimension(usize),
+    Weight(usize),
+}
+
+/// Ties between equally informative questions go to the usual prompt order.
+const QUESTIONS: [Question; 10] = [
+    Question::Dimension(2),
+    Question::Dimension(3),
+    Question::Dimension(0),
+    Question::Dimension(1),
+    Question::Dimension(4),
+    Question::Weight(0),
+    Question::Weight(1),
+    Question::Weight(2),
+    Question::Weight(3),
+    Question::Weight(4),
+];
+
+impl Question {
+    pub fn label(&self) -> String {
+        match self {
+            Question::Dimension(i) => CRITERIA[*i].to_string(),
+            Question::Weight(i) => format!("{} weight", CRITERIA[*i]),
+        }
+    }
+}
+
+/// One way the unanswered questions could still be answered, and the
+/// architecture that would then come out on top.
+struct Outcome<'a> {
+    values: [&'a str; 5],
+    weights: [i32; 5],
+    top: &'static str,
+}
+
+/// Chooses the next core question from the answers so far and stops once the
+/// remaining answers cannot change the top recommendation.
+pub struct Planner<F: Fn(&Scenario, &Weights) -> Option<&'static str>> {
+    top: F,
+    values: [Option<String>; 5],
+    weights: [Option<i32>; 5],
+}
+
+impl<F: Fn(&Scenario, &Weights) -> Option<&'static str>> Planner<F> {
+    /// `top` returns the best architecture for a complete scenario.
+    pub fn new(top: F) -> Self {
+        Planner {
+            top,
+            values: Default::default(),
+            weights: [None; 5],
+        }
+    }
+
+    pub fn answer_value(&mut self, dimension: usize, value: String) {
+        self.values[dimension] = Some(value);
+    }
+
+    pub fn answer_weight(&mut self, criterion: usize, weight: i32) {
+        self.weights[criterion] = Some(weight);
+    }
+
+    fn is_answered(&self, question: Question) -> bool {
+        match question {
+            Question::Dimension(i) => self.values[i].is_some(),
+            Question::Weight(i) => self.weights[i].is_some(),
+        }
+    }
+
+    fn scenario(values: &[&str; 5]) -> Scenario {
+        let [sla, budget, volume, workload, observability] = values.map(str::to_string);
+        Scenario {
+            volume,
+            workload,
+            sla,
+            budget,
+            observability,
+            ..Scenario::default()
+        }
+    }
+
+    /// Every completion of the unanswered questions with its top architecture.
+    fn outcomes(&self) -> Vec<Outcome<'_>> {
+        let mut partial = vec![([""; 5], [0; 5])];
+        for i in 0..5 {
+            let values: Vec<&str> = match &self.values[i] {
+                Some(v) => vec![v.as_str()],
+                None => VALUES[i].to_vec(),
+            };
+            let weights = match self.weights[i] {
+                Some(w) => vec![w],
+                None => WEIGHT_RANGE.to_vec(),
+            };
+            partial = partial
+                .into_iter()
+                .flat_map(|(v, w)| {
+                    let weights = &weights;
+                    values.iter().flat_map(move |value| {
+                        weights.iter().map(move |weight| {
+                            let (mut v, mut w) = (v, w);
+                            v[i] = value;
+                            w[i] = *weight;
+                            (v, w)
+                        })
+                    })
+                })
+                .collect();
+        }
+        partial
+            .into_iter()
+            .filter_map(|(values, weights)| {
+                let top = (self.top)(&Self::scenario(&values), &Weights::from_array(weights))?;
+                Some(Outcome {
+                    values,
+                    weights,
+                    top,
+                })
+            })
+            .collect()
+    }
+
+    /// Architectures that can still end up on top, alphabetically.
+    pub fn candidates(&self) -> Vec<&'static str> {
+        distinct(self.outcomes().iter())
+    }
+
+    /// The unanswered question whose answers leave the fewest candidates on
+    /// average, or None when the top recommendation is already settled.
+    pub fn next_question(&self) -> Option<Question> {
+        let outcomes = self.outcomes();
+        if distinct(outcomes.iter()).len() <= 1 {
+            return None;
+        }
+        let remaining = |question: Question| {
+            let groups: Vec<Vec<&Outcome>> = match question {
+                Question::Dimension(i) => VALUES[i]
+                    .iter()
+                    .map(|v| outcomes.iter().filter(|o| o.values[i] == *v).collect())
+                    .collect(),
+                Question::Weight(i) => WEIGHT_RANGE
+                    .iter()
+                    .map(|w| outcomes.iter().filter(|o| o.weights[i] == *w).collect())
+                    .collect(),
+            };
+            let total: usize = groups
+                .iter()
+                .map(|g| distinct(g.iter().copied()).len())
+                .sum();
+            total as f64 / groups.len() as f64
+        };
+        QUESTIONS
+            .into_iter()
+            .filter(|q| !self.is_answered(*q))
+            .map(|q| (q, remaining(q)))
+            .fold(None, |best: Option<(Question, f64)>, (q, r)| match best {
+                Some((_, best_r)) if best_r <= r => best,
+                _ => Some((q, r)),
+            })
+            .map(|(q, _)| q)
+    }
+
+    /// Questions that were not asked, in prompt order.
+    pub fn skipped(&self) -> Vec<Question> {
+        QUESTIONS
+            .into_iter()
+            .filter(|q| !self.is_answered(*q))
+            .collect()
+    }
+
+    /// Answers in `Weights` order; skipped dimensions take their first value.
+    pub fn values(&self) -> [String; 5] {
+        let mut result: [String; 5] = Default::default();
+        for (i, value) in result.iter_mut().enumerate() {
+            *value = self.values[i]
+                .clone()
+                .unwrap_or_else(|| VALUES[i][0].to_string());
+        }
+        result
+    }
+
+    /// Answered weights, with the defaults for skipped ones.
+    pub fn weights(&self) -> Weights {
+        let defaults = Weights::default().as_array();
+        let mut result = [0; 5];
+        for (i, weight) in result.iter_mut().enumerate() {
+            *weight = self.weights[i].unwrap_or(defaults[i]);
+        }
+        Weights::from_array(result)
+    }
+}
+
+fn distinct<'a: 'b, 'b>(outcomes: impl Iterator<Item = &'b Outcome<'a>>) -> Vec<&'static str> {
+    outcomes
+        .map(|o| o.top)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+pub fn print_skipped<F: Fn(&Scenario, &Weights) -> Option<&'static str>>(planner: &Planner<F>) {
+    let skipped = planner.skipped();
+    if skipped.is_empty() {
+        return;
+    }
+    let top = planner.candidates().join(", ");
+    let values = planner.values();
+    let weights = planner.weights().as_array();
+    println!("\n=== Skipped Questions ===");
+    for question in skipped {
+        match question {
+            Question::Dimension(i) => println!(
+                "- {}: any answer keeps {} on top (assumed {})",
+                question.label(),
+                top,
+                values[i]
+            ),
+            Question::Weight(i) => println!(
+                "- {}: any weight from {} to {} keeps {} on top (default {} used)",
+                question.label(),
+                WEIGHT_RANGE[0],
+                WEIGHT_RANGE[1],
+                top,
+                weights[i]
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::ArchProfile;
+    use crate::similarity_score;
+
+    fn profile(name: &'static str, workload: &'static str, sla: &'static str) -> ArchProfile {
+        ArchProfile {
+            name,
+            volume: "any",
+            workload,
+            sla,
+            budget: "any",
+            observability: "any",
+            base: 80,
+        }
+    }
+
+    fn planner(
+        catalog: Vec<ArchProfile>,
+    ) -> Planner<impl Fn(&Scenario, &Weights) -> Option<&'static str>> {
+        Planner::new(move |scenario: &Scenario, weights: &Weights| {
+            let mut best: Option<(&'static str, i32)> = None;
+            for p in &catalog {
+                let score = similarity_score(scenario.as_tuple(), weights.as_tuple(), p);
+                if best.is_none_or(|(_, s)| score > s) {
+                    best = Some((p.name, score));
+                }
+            }
+            best.map(|(name, _)| name)
+        })
+    }
+
+    #[test]
+    fn test_single_candidate_skips_everything() {
+        let planner = planner(vec![profile("only", "any", "any")]);
+        assert_eq!(planner.next_question(), None);
+        assert_eq!(planner.skipped().len(), 10);
+        assert_eq!(planner.weights(), Weights::default());
+    }
+
+    #[test]
+    fn test_asks_the_splitting_question_and_stops() {
+        // Only SLA separates the profiles, so it is asked first; once it is
+        // answered nothing else can change the top recommendation.
+        let mut planner = planner(vec![
+            profile("fast", "any", "high"),
+            profile("cheap", "any", "low"),
+        ]);
+        assert_eq!(planner.candidates(), vec!["cheap", "fast"]);
+        assert_eq!(planner.next_question(), Some(Question::Dimension(0)));
+        planner.answer_value(0, "high".to_string());
+        assert_eq!(planner.next_question(), None);
+        assert_eq!(planner.candidates(), vec!["fast"]);
+        assert_eq!(planner.skipped().len(), 9);
+    }
+
+    #[test]
+    fn test_weight_asked_when_it_decides() {
+        // "streamer" matches the workload and "guarded" the SLA, so which one
+        // wins depends on how the two criteria are weighted.
+        let mut planner = planner(vec![
+            profile("streamer", "streaming", "low"),
+            profile("guarded", "batch", "high"),
+        ]);
+        planner.answer_value(3, "streaming".to_string());
+        planner.answer_value(0, "high".to_string());
+        let next = planner.next_question();
+        assert!(matches!(next, Some(Question::Weight(0 | 3))), "{:?}", next);
+        planner.answer_weight(0, 9);
+        planner.answer_weight(3, 1);
+        assert_eq!(planner.next_question(), None);
+        assert_eq!(planner.candidates(), vec!["guarded"]);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::catalog::ArchProfile;
+use crate::scenario::{Scenario, Weights};
+use crate::similarity_score;
+
+// Fit of a profile's value for a criterion: exact match, "any", or mismatch.
+const FIT_MATCH: f64 = 1.0;
+const FIT_ANY: f64 = 0.5;
+const FIT_MISMATCH: f64 = 0.0;
+
+// Weighted product: a mismatch counts as this fit so that one bad criterion
+// drags the score down without zeroing every mismatching profile alike.
+const PRODUCT_FLOOR: f64 = 0.1;
+
+// ELECTRE: a outranks b when the criteria favouring a carry at least this
+// share of the weight and no criterion favours b by more than the veto.
+const CONCORDANCE: f64 = 0.65;
+const DISCORDANCE_VETO: f64 = 0.5;
+
+fn fit(arch: &str, user: &str) -> f64 {
+    if arch == "any" {
+        FIT_ANY
+    } else if arch == user {
+        FIT_MATCH
+    } else {
+        FIT_MISMATCH
+    }
+}
+
+/// Per-criterion fit (0..1) of a profile to the scenario, in `Weights` order.
+pub fn fits(profile: &ArchProfile, scenario: &Scenario) -> [f64; 5] {
+    [
+        fit(profile.sla, &scenario.sla),
+        fit(profile.budget, &scenario.budget),
+        fit(profile.volume, &scenario.volume),
+        fit(profile.workload, &scenario.workload),
+        fit(profile.observability, &scenario.observability),
+    ]
+}
+
+/// Multi-criteria method that scores catalog profiles against a scenario.
+pub trait RankingStrategy {
+    fn name(&self) -> &'static str;
+
+    /// Whether scores are base plus the per-criterion profile points, so that
+    /// a per-criterion breakdown adds up to them.
+    fn is_additive(&self) -> bool {
+        false
+    }
+
+    /// Scores for every profile, in the order given. Scores share the
+    /// additive scale (base plus up to 10 points per unit of weight), so that
+    /// adjustments apply the same way to every strategy.
+    fn scores(&self, profiles: &[ArchProfile], scenario: &Scenario, weights: &Weights) -> Vec<i32>;
+}
+
+/// Strategies that compute a closeness (0..1) per profile from the fit matrix.
+trait Closeness {
+    fn closeness(&self, fits: &[[f64; 5]], weights: &[f64; 5]) -> Vec<f64>;
+}
+
+fn closeness_scores(
+    method: &dyn Closeness,
+    profiles: &[ArchProfile],
+    scenario: &Scenario,
+    weights: &Weights,
+) -> Vec<i32> {
+    let matrix: Vec<_> = profiles.iter().map(|p| fits(p, scenario)).collect();
+    let sum = weights.sum().max(1) as f64;
+    let normalized = weights.as_array().map(|w| w.max(0) as f64 / sum);
+    method
+        .closeness(&matrix, &normalized)
+        .iter()
+        .zip(profiles)
+        .map(|(c, p)| p.base + (c * 10.0 * sum).round() as i32)
+        .collect()
+}
+
+/// The original weighted sum of `similarity_score`.
+pub struct Additive;
+
+impl RankingStrategy for Additive {
+    fn name(&self) -> &'static str {
+        "additive"
+    }
+
+    fn is_additive(&self) -> bool {
+        true
+    }
+
+    fn scores(&self, profiles: &[ArchProfile], scenario: &Scenario, weights: &Weights) -> Vec<i32> {
+        profiles
+            .iter()
+            .map(|p| similarity_score(scenario.as_tuple(), weights.as_tuple(), p))
+            .collect()
+    }
+}
+
+/// Weighted product: fits multiplied with weights as exponents, so a poor
+/// fit on any criterion cannot be compensated elsewhere.
+pub struct WeightedProduct;
+
+impl Closeness for WeightedProduct {
+    fn closeness(&self, fits: &[[f64; 5]], weights: &[f64; 5]) -> Vec<f64> {
+        fits.iter()
+            .map(|row| {
+                row.iter()
+                    .zip(weights)
+                    .map(|(f, w)| f.max(PRODUCT_FLOOR).powf(*w))
+                    .product()
+            })
+            .collect()
+    }
+}
+
+impl RankingStrategy for WeightedProduct {
+    fn name(&self) -> &'static str {
+        "weighted product"
+    }
+
+    fn scores(&self, profiles: &[ArchProfile], scenario: &Scenario, weights: &Weights) -> Vec<i32> {
+        closeness_scores(self, profiles, scenario, weights)
+    }
+}
+
+/// TOPSIS: relative closeness to the ideal and distance from the anti-ideal
+/// profile of the catalog.
+pub struct Topsis;
+
+impl Closeness for Topsis {
+    fn closeness(&self, fits: &[[f64; 5]], weights: &[f64; 5]) -> Vec<f64> {
+        let mut norms = [0.0; 5];
+        for (j, norm) in norms.iter_mut().enumerate() {
+            *norm = fits.iter().map(|row| row[j] * row[j]).sum::<f64>().sqrt();
+        }
+        let weighted: Vec<[f64; 5]> = fits
+            .iter()
+            .map(|row| {
+                let mut v = [0.0; 5];
+                for j in 0..5 {
+                    if norms[j] > 0.0 {
+                        v[j] = weights[j] * row[j] / norms[j];
+                    }
+                }
+                v
+            })
+            .collect();
+        let mut best = [f64::MIN; 5];
+        let mut worst = [f64::MAX; 5];
+        for v in &weighted {
+            for j in 0..5 {
+                best[j] = best[j].max(v[j]);
+                worst[j] = worst[j].min(v[j]);
+            }
+        }
+        let distance = |v: &[f64; 5], to: &[f64; 5]| {
+            v.iter()
+                .zip(to)
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        };
+        weighted
+            .iter()
+            .map(|v| {
+                let (d_best, d_worst) = (distance(v, &best), distance(v, &worst));
+                if d_best + d_worst == 0.0 {
+                    1.0
+                } else {
+                    d_worst / (d_best + d_worst)
+                }
+            })
+            .collect()
+    }
+}
+
+impl RankingStrategy for Topsis {
+    fn name(&self) -> &'static str {
+        "TOPSIS"
+    }
+
+    fn scores(&self, profiles: &[ArchProfile], scenario: &Scenario, weights: &Weights) -> Vec<i32> {
+        closeness_scores(self, profiles, scenario, weights)
+    }
+}
+
+/// ELECTRE-style outranking: pairwise concordance and discordance tests,
+/// scored by net outranking flow (outranked others minus outranked by).
+pub struct Electre;
+
+impl Electre {
+    fn outranks(a: &[f64; 5], b: &[f64; 5], weights: &[f64; 5]) -> bool {
+        let concordance: f64 = (0..5).filter(|&j| a[j] >= b[j]).map(|j| weights[j]).sum();
+        // A criterion weighted 0 is ignored, so it cannot veto either.
+        let discordance = (0..5)
+            .filter(|&j| weights[j] > 0.0)
+            .map(|j| b[j] - a[j])
+            .fold(0.0, f64::max);
+        concordance >= CONCORDANCE && discordance <= DISCORDANCE_VETO
+    }
+}
+
+impl Closeness for Electre {
+    fn closeness(&self, fits: &[[f64; 5]], weights: &[f64; 5]) -> Vec<f64> {
+        let others = (fits.len().max(2) - 1) as f64;
+        fits.iter()
+            .enumerate()
+            .map(|(i, a)| {
+                let mut flow = 0.0;
+                for (k, b) in fits.iter().enumerate() {
+                    if i == k {
+                        continue;
+                    }
+                    if Self::outranks(a, b, weights) {
+                        flow += 1.0;
+                    }
+                    if Self::outranks(b, a, weights) {
+                        flow -= 1.0;
+                    }
+                }
+                (flow / others + 1.0) / 2.0
+            })
+            .collect()
+    }
+}
+
+impl RankingStrategy for Electre {
+    fn name(&self) -> &'static str {
+        "ELECTRE"
+    }
+
+    fn scores(&self, profiles: &[ArchProfile], scenario: &Scenario, weights: &Weights) -> Vec<i32> {
+        closeness_scores(self, profiles, scenario, weights)
+    }
+}
+
+/// Strategy selected with `--ranking`.
+pub fn parse(input: &str) -> Option<Box<dyn RankingStrategy>> {
+    match input.trim().to_lowercase().as_str() {
+        "additive" => Some(Box::new(Additive)),
+        "product" | "wpm" => Some(Box::new(WeightedProduct)),
+        "topsis" => Some(Box::new(Topsis)),
+        "electre" => Some(Box::new(Electre)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::profiles;
+
+    fn scenario() -> Scenario {
+        Scenario {
+            volume: "large".to_string(),
+            workload: "batch".to_string(),
+            sla: "low".to_string(),
+            budget: "low".to_string(),
+            observability: "low".to_string(),
+            ..Scenario::default()
+        }
+    }
+
+    fn profile(name: &'static str, values: [&'static str; 5]) -> ArchProfile {
+        let [sla, budget, volume, workload, observability] = values;
+        ArchProfile {
+            name,
+            volume,
+            workload,
+            sla,
+            budget,
+            observability,
+            base: 80,
+        }
+    }
+
+    #[test]
+    fn test_additive_matches_similarity_score() {
+        let catalog = profiles();
+        let weights = Weights::default();
+        let scores = Additive.scores(&catalog, &scenario(), &weights);
+        for (p, s) in catalog.iter().zip(&scores) {
+            assert_eq!(
+                *s,
+                similarity_score(scenario().as_tuple(), weights.as_tuple(), p)
+            );
+        }
+    }
+
+    #[test]
+    fn test_product_punishes_single_bad_fit() {
+        // "spiky" matches the heavy SLA weight but misses everything else;
+        // "balanced" is "any" everywhere.
+        let catalog = vec![
+            profile("spiky", ["low", "high", "small", "streaming", "high"]),
+            profile("balanced", ["any", "any", "any", "any", "any"]),
+        ];
+        let weights = Weights::from_array([9, 1, 1, 1, 1]);
+        let additive = Additive.scores(&catalog, &scenario(), &weights);
+        assert!(additive[0] > additive[1]);
+        let product = WeightedProduct.scores(&catalog, &scenario(), &weights);
+        assert!(product[0] < product[1]);
+    }
+
+    #[test]
+    fn test_topsis_and_electre_rank_dominant_profile_first() {
+        let catalog = vec![
+            profile("worst", ["high", "high", "small", "streaming", "high"]),
+            profile("best", ["low", "low", "large", "batch", "low"]),
+            profile("middle", ["any", "any", "any", "any", "any"]),
+        ];
+        let weights = Weights::default();
+        for strategy in [parse("topsis").unwrap(), parse("electre").unwrap()] {
+            let scores = strategy.scores(&catalog, &scenario(), &weights);
+            assert!(scores[1] > scores[2], "{}", strategy.name());
+            assert!(scores[2] > scores[0], "{}", strategy.name());
+        }
+        assert!(parse("borda").is_none());
+    }
+
+    #[test]
+    fn test_electre_ignores_zero_weight_veto() {
+        // `a` fits every criterion but the first, where `b` fits fully.
+        let a = [0.0, 1.0, 1.0, 1.0, 1.0];
+        let b = [1.0, 0.0, 0.0, 0.0, 0.0];
+        assert!(!Electre::outranks(&a, &b, &[0.2; 5]));
+        assert!(Electre::outranks(&a, &b, &[0.0, 0.25, 0.25, 0.25, 0.25]));
+    }
+}
@@ -0,0 +1,564 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::ahp::CRITERIA;
+use crate::catalog::{profiles_for, ACCESS_PATTERNS, SOURCES};
+use crate::compliance::{REGIMES, REGIONS};
+use crate::cost::PriceTable;
+use crate::formats::UPDATES;
+use crate::ml::ML_NEEDS;
+use crate::questionnaire::VALUES;
+use crate::ranking::{fits, RankingStrategy};
+use crate::scenario::{parse_budget, parse_list, parse_volume, Scenario, ScenarioFile, Weights};
+use crate::security::POSTURES;
+use crate::team::SKILLS;
+use crate::{adjustments, latency, Explanation, MULTI_TOP};
+
+const HELP: &str = "\
+Commands:
+  set <input> <value>     change an input, e.g. `set sla low`, `set sources oltp,logs`
+  weight <criterion> <n>  change a weight, e.g. `weight budget 8`
+  rank                    show the ranking
+  explain <architecture>  show why an architecture scores as it does
+  diff [name]             compare with the state before the last change, or a saved one
+  save <name>             keep the current state and write it to <name>.json
+  show                    list the current inputs and weights
+  help, quit
+";
+
+/// Inputs accepted by `set`, as named in the prompts.
+const INPUTS: [&str; 19] = [
+    "volume",
+    "workload",
+    "sla",
+    "budget",
+    "observability",
+    "compliance",
+    "residency",
+    "latency",
+    "availability",
+    "team",
+    "skills",
+    "ops",
+    "access",
+    "sources",
+    "ml",
+    "quality",
+    "schema",
+    "updates",
+    "security",
+];
+
+pub type State = (Scenario, Weights);
+
+/// In-memory what-if session: the scenario and weights survive between
+/// commands and the ranking is shown again after every change.
+pub struct Session<'a> {
+    scenario: Scenario,
+    weights: Weights,
+    previous: Option<State>,
+    saved: BTreeMap<String, State>,
+    multi: bool,
+    strategy: &'a dyn RankingStrategy,
+    prices: &'a PriceTable,
+    /// Where `save` writes `<name>.json`; empty for the working directory.
+    dir: PathBuf,
+}
+
+impl<'a> Session<'a> {
+    pub fn new(
+        scenario: Scenario,
+        weights: Weights,
+        multi: bool,
+        strategy: &'a dyn RankingStrategy,
+        prices: &'a PriceTable,
+    ) -> Self {
+        Session {
+            scenario,
+            weights,
+            previous: None,
+            saved: BTreeMap::new(),
+            multi,
+            strategy,
+            prices,
+            dir: PathBuf::new(),
+        }
+    }
+
+    fn ranked(&self, (scenario, weights): &State) -> Vec<(&'static str, i32)> {
+        let ranked = crate::rank(scenario, weights, self.multi, self.strategy);
+        if self.multi {
+            ranked.into_iter().take(MULTI_TOP).collect()
+        } else {
+            ranked
+        }
+    }
+
+    fn state(&self) -> State {
+        (self.scenario.clone(), self.weights)
+    }
+
+    /// Runs one command line and returns what to print.
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("").to_lowercase();
+        let rest: Vec<&str> = words.collect();
+        match (command.as_str(), rest.as_slice()) {
+            ("set", [input, value @ ..]) => {
+                let before = self.state();
+                set_input(
+                    &mut self.scenario,
+                    &input.to_lowercase(),
+                    &value.join(" ").to_lowercase(),
+                    self.prices,
+                )?;
+                Ok(self.changed(before))
+            }
+            ("weight", [criterion, value]) => {
+                let index = CRITERIA
+                    .iter()
+                    .position(|c| c.eq_ignore_ascii_case(criterion))
+                    .ok_or_else(|| {
+                        format!(
+                            "unknown criterion {} ({})",
+                            criterion,
+                            CRITERIA.join("/").to_lowercase()
+                        )
+                    })?;
+                let value = value
+                    .parse::<i32>()
+                    .map_err(|_| format!("weight must be a whole number, got {}", value))?;
+                let before = self.state();
+                let mut weights = self.weights.as_array();
+                weights[index] = value;
+                self.weights = Weights::from_array(weights);
+                Ok(self.changed(before))
+            }
+            ("rank", []) => Ok(self.ranking(None)),
+            ("explain", name) if !name.is_empty() => self.explain(&name.join(" ")),
+            ("diff", []) => match &self.previous {
+                Some(previous) => Ok(self.diff(previous)),
+                None => Err("nothing changed yet".to_string()),
+            },
+            ("diff", [name]) => match self.saved.get(*name) {
+                Some(saved) => Ok(self.diff(saved)),
+                None => Err(format!("no saved state named {}", name)),
+            },
+            ("save", [name]) => self.save(name),
+            ("show", []) => Ok(inputs(&self.state())
+                .iter()
+                .map(|(k, v)| format!("  {}: {}\n", k, v))
+                .collect()),
+            ("help", _) => Ok(HELP.to_string()),
+            _ => Err(format!("cannot parse `{}`; type help for commands", line)),
+        }
+    }
+
+    fn changed(&mut self, before: State) -> String {
+        let ranking = self.ranking(Some(&before));
+        self.previous = Some(before);
+        ranking
+    }
+
+    /// The ranking, with score and position changes against `before`.
+    fn ranking(&self, before: Option<&State>) -> String {
+        let now = self.ranked(&self.state());
+        if now.is_empty() {
+            return "  (no recommendations; set more inputs)\n".to_string();
+        }
+        let old = before.map(|b| self.ranked(b));
+        let mut out = String::new();
+        for (i, (name, score)) in now.iter().enumerate() {
+            let change = match &old {
+                None => String::new(),
+                Some(old) => match old.iter().position(|(n, _)| n == name) {
+                    None => ", new".to_string(),
+                    Some(j) => movement(j, i, old[j].1, *score),
+                },
+            };
+            out.push_str(&format!("  {}. {} ({}{})\n", i + 1, name, score, change));
+        }
+        out
+    }
+
+    fn explain(&self, query: &str) -> Result<String, String> {
+        let candidates = profiles_for(&self.scenario);
+        let ranked = crate::rank(&self.scenario, &self.weights, self.multi, self.strategy);
+        let query = query.to_lowercase();
+        let names: Vec<&'static str> = candidates
+            .iter()
+            .map(|p| p.name)
+            .chain(ranked.iter().map(|(n, _)| *n))
+            .collect();
+        let name = names
+            .iter()
+            .find(|n| n.to_lowercase() == query)
+            .or_else(|| names.iter().find(|n| n.to_lowercase().starts_with(&query)))
+            .copied()
+            .ok_or_else(|| format!("no architecture matches {}", query))?;
+
+        let mut out = match ranked.iter().position(|(n, _)| *n == name) {
+            Some(i) => format!("{}: #{} with score {}\n", name, i + 1, ranked[i].1),
+            None => format!("{}: not recommended\n", name),
+        };
+        for line in explanation(&self.scenario, &self.weights, name) {
+            out.push_str(&format!("  {}\n", line));
+        }
+        Ok(out)
+    }
+
+    /// Input differences first, then how the ranking moved because of them.
+    fn diff(&self, other: &State) -> String {
+        let (old, new) = (inputs(other), inputs(&self.state()));
+        let mut out = String::from("Inputs:\n");
+        let changes: Vec<String> = new
+            .iter()
+            .filter(|(k, v)| old.get(*k) != Some(v))
+            .map(|(k, v)| format!("  {}: {} -> {}\n", k, old[k], v))
+            .collect();
+        if changes.is_empty() {
+            out.push_str("  (no differences)\n");
+        }
+        out.extend(changes);
+        out.push_str("Ranking:\n");
+        out.push_str(&self.ranking(Some(other)));
+        out
+    }
+
+    fn save(&mut self, name: &str) -> Result<String, String> {
+        if name.contains(['/', '\\']) {
+            return Err(format!("save name {} must not contain a path", name));
+        }
+        self.saved.insert(name.to_string(), self.state());
+        let file = ScenarioFile {
+            scenario: self.scenario.clone(),
+            weights: Some(self.weights),
+            stakeholders: vec![],
+        };
+        let path = self.dir.join(format!("{}.json", name));
+        let json = serde_json::to_string_pretty(&file).expect("scenario is serializable");
+        std::fs::write(&path, json).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(format!(
+            "saved {} to {} (reload with --scenario)\n",
+            name,
+            path.display()
+        ))
+    }
+}
+
+/// Fit and weight per criterion, rule explanations and score adjustments of
+/// an architecture, one line each.
+pub fn explanation(scenario: &Scenario, weights: &Weights, name: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(profile) = profiles_for(scenario).iter().find(|p| p.name == name) {
+        let values = [
+            profile.sla,
+            profile.budget,
+            profile.volume,
+            profile.workload,
+            profile.observability,
+        ];
+        for (((criterion, fit), value), weight) in CRITERIA
+            .iter()
+            .zip(fits(profile, scenario))
+            .zip(values)
+            .zip(weights.as_array())
+        {
+            lines.push(format!(
+                "{}: profile {}, fit {} x weight {}",
+                criterion, value, fit, weight
+            ));
+        }
+    }
+    let (_, explanations) = crate::evaluate_strict(scenario, weights);
+    for Explanation(_, text) in explanations.iter().filter(|e| e.0 == name) {
+        lines.push(format!("- {}", text));
+    }
+    for a in adjustments::for_architecture(scenario, weights, name) {
+        lines.push(format!("{:+}: {}", a.points, a.reason));
+    }
+    lines
+}
+
+fn movement(from: usize, to: usize, old_score: i32, score: i32) -> String {
+    let mut change = String::new();
+    if score != old_score {
+        change.push_str(&format!(", {:+}", score - old_score));
+    }
+    if to < from {
+        change.push_str(&format!(", up {}", from - to));
+    } else if to > from {
+        change.push_str(&format!(", down {}", to - from));
+    }
+    change
+}
+
+/// Changes one input; values that no rule understands are rejected rather
+/// than cleared, and `none` clears an optional input explicitly.
+pub fn set_input(
+    scenario: &mut Scenario,
+    input: &str,
+    value: &str,
+    prices: &PriceTable,
+) -> Result<(), String> {
+    let invalid = |expected: &str| format!("invalid {} {} (expected {})", input, value, expected);
+    let choice = |dimension: usize| {
+        let values = VALUES[dimension];
+        if values.contains(&value) {
+            Ok(value.to_string())
+        } else {
+            Err(invalid(&values.join("/")))
+        }
+    };
+    let optional = |known: &[&str]| match Some(value).filter(|v| !v.is_empty() && *v != "none") {
+        Some(v) if !known.contains(&v) => Err(invalid(&format!("{} or none", known.join("/")))),
+        v => Ok(v.map(str::to_string)),
+    };
+    let list = |known: &[&str]| {
+        let items = match value {
+            "none" => Vec::new(),
+            _ => parse_list(value),
+        };
+        match items.iter().find(|i| !known.contains(&i.as_str())) {
+            Some(item) => Err(format!(
+                "invalid {} {} (expected {}, comma-separated, or none)",
+                input,
+                item,
+                known.join("/")
+            )),
+            None => Ok(items),
+        }
+    };
+    // Ops maturity, data quality and schema volatility use the observability levels.
+    let levels = VALUES[4];
+    let mut patterns: Vec<&str> = ACCESS_PATTERNS
+        .iter()
+        .flat_map(|(_, p)| *p)
+        .copied()
+        .collect();
+    patterns.sort_unstable();
+    patterns.dedup();
+    let number = |parsed: Option<f64>, expected: &str| match (value, parsed) {
+        ("none", _) => Ok(None),
+        (_, Some(v)) => Ok(Some(v)),
+        (_, None) => Err(invalid(expected)),
+    };
+    match input {
+        "volume" => {
+            let (volume, volume_tb) = parse_volume(value);
+            if volume_tb.is_none() {
+                choice(2)?;
+            }
+            (scenario.volume, scenario.volume_tb) = (volume, volume_tb);
+        }
+        "workload" => scenario.workload = choice(3)?,
+        "sla" => scenario.sla = choice(0)?,
+        "budget" => {
+            let (budget, monthly) = parse_budget(value, prices.high_budget_threshold);
+            if monthly.is_none() {
+                choice(1)?;
+            }
+            (scenario.budget, scenario.budget_monthly) = (budget, monthly);
+        }
+        "observability" => scenario.observability = choice(4)?,
+        "compliance" => scenario.compliance = list(REGIMES)?,
+        "residency" => {
+            scenario.residency = match value {
+                "any" => None,
+                _ => optional(REGIONS)?,
+            }
+        }
+        "latency" => {
+            scenario.latency_ms = number(
+                latency::parse_latency(value),
+                "a latency such as 200ms, 5s or 15m, or none",
+            )?
+        }
+        "availability" => {
+            scenario.availability = number(
+                latency::parse_availability(value),
+                "a percentage such as 99.9 or nines such as 3, or none",
+            )?
+        }
+        "team" => {
+            scenario.team_size = match value {
+                "none" => None,
+                _ => Some(
+                    value
+                        .parse::<u32>()
+                        .map_err(|_| invalid("a number of engineers, or none"))?,
+                ),
+            }
+        }
+        "skills" => scenario.skills = list(SKILLS)?,
+        "ops" => scenario.ops_maturity = optional(levels)?,
+        "access" => scenario.access_patterns = list(&patterns)?,
+        "sources" => scenario.sources = list(SOURCES)?,
+        "ml" => scenario.ml_needs = list(ML_NEEDS)?,
+        "quality" => scenario.data_quality = optional(levels)?,
+        "schema" => scenario.schema_volatility = optional(levels)?,
+        "updates" => scenario.updates = optional(UPDATES)?,
+        "security" => scenario.security = list(POSTURES)?,
+        _ => return Err(format!("unknown input {} ({})", input, INPUTS.join("/"))),
+    }
+    Ok(())
+}
+
+/// Every input and weight as display text, keyed by its scenario-file name.
+pub fn inputs((scenario, weights): &State) -> BTreeMap<String, String> {
+    let mut result = BTreeMap::new();
+    for (prefix, value) in [
+        ("", serde_json::to_value(scenario)),
+        ("weight ", serde_json::to_value(weights)),
+    ] {
+        if let Ok(Value::Object(map)) = value {
+            for (key, value) in map {
+                result.insert(format!("{}{}", prefix, key), display(&value));
+            }
+        }
+    }
+    result
+}
+
+pub fn display(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) if s.is_empty() => "-".to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.is_empty() => "-".to_string(),
+        Value::Array(items) => items.iter().map(display).collect::<Vec<_>>().join(","),
+        Value::Object(map) if map.is_empty() => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Reads commands until `quit` or end of input.
+pub fn run(session: &mut Session) {
+    println!("What-if session; type help for commands.");
+    let stdin = std::io::stdin();
+    loop {
+        print!("> ");
+        std::io::stdout().flush().ok();
+        let mut line = String::new();
+        if stdin.read_line(&mut line).unwrap_or(0) == 0 {
+            break;
+        }
+        let line = line.trim();
+        match line {
+            "" => continue,
+            "quit" | "exit" => break,
+            _ => match session.execute(line) {
+                Ok(output) => print!("{}", output),
+                Err(e) => println!("! {}", e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ranking::Additive;
+
+    fn session(prices: &PriceTable) -> Session<'_> {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "batch".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "medium".to_string(),
+            ..Scenario::default()
+        };
+        Session::new(scenario, Weights::default(), true, &Additive, prices)
+    }
+
+    #[test]
+    fn test_set_and_weight_update_state() {
+        let prices = PriceTable::default();
+        let mut session = session(&prices);
+        assert!(session.execute("diff").is_err());
+        let ranking = session.execute("set volume 500tb").unwrap();
+        assert!(ranking.starts_with("  1. "));
+        assert_eq!(session.scenario.volume_tb, Some(500.0));
+        session.execute("weight Budget 8").unwrap();
+        assert_eq!(session.weights.budget, 8);
+        assert!(session.execute("weight speed 8").is_err());
+        assert!(session.execute("set colour blue").is_err());
+    }
+
+    #[test]
+    fn test_set_rejects_invalid_values() {
+        let prices = PriceTable::default();
+        let mut session = session(&prices);
+        session.execute("set latency 200ms").unwrap();
+        session.execute("set team 4").unwrap();
+        for command in [
+            "set latency 5x",
+            "set availability high",
+            "set team abc",
+            "set sla hgih",
+            "set workload nightly",
+            "set observability full",
+            "set volume huge",
+            "set budget plenty",
+            "set ml trainnig",
+            "set sources oltp,mainframe",
+            "set compliance sox",
+            "set residency de",
+            "set ops expert",
+            "set updates daily",
+            "set security airgap",
+            "set access graph",
+            "set skills cobol",
+        ] {
+            assert!(session.execute(command).is_err(), "{}", command);
+        }
+        assert_eq!(session.scenario.latency_ms, Some(200.0));
+        assert_eq!(session.scenario.team_size, Some(4));
+        assert_eq!(session.scenario.sla, "high");
+        session.execute("set latency none").unwrap();
+        session.execute("set team none").unwrap();
+        assert_eq!(session.scenario.latency_ms, None);
+        assert_eq!(session.scenario.team_size, None);
+        session.execute("set ml training,inference").unwrap();
+        session.execute("set residency eu").unwrap();
+        assert_eq!(session.scenario.residency.as_deref(), Some("eu"));
+        session.execute("set ml none").unwrap();
+        assert!(session.scenario.ml_needs.is_empty());
+    }
+
+    #[test]
+    fn test_diff_lists_changed_inputs() {
+        let prices = PriceTable::default();
+        let mut session = session(&prices);
+        session.dir = std::env::temp_dir().join(format!("repl-test-{}", std::process::id()));
+        std::fs::create_dir_all(&session.dir).unwrap();
+        assert!(session.execute("save ../base").is_err());
+        session.execute("save base").unwrap();
+        let file = ScenarioFile::load(session.dir.join("base.json").to_str().unwrap()).unwrap();
+        std::fs::remove_dir_all(&session.dir).ok();
+        assert_eq!(file.weights, Some(Weights::default()));
+        session.execute("set sla low").unwrap();
+        session.execute("weight sla 9").unwrap();
+        // `diff` compares with the state before the last change only.
+        let last = session.execute("diff").unwrap();
+        assert!(last.contains("weight sla: 5 -> 9"));
+        assert!(!last.contains("  sla: high -> low"));
+        let since_save = session.execute("diff base").unwrap();
+        assert!(since_save.contains("  sla: high -> low"));
+        assert!(since_save.contains("weight sla: 5 -> 9"));
+    }
+
+    #[test]
+    fn test_explain_matches_name_prefix() {
+        let prices = PriceTable::default();
+        let session = session(&prices);
+        let text = session.explain("lakeh").unwrap();
+        assert!(text.starts_with("Lakehouse: #"));
+        assert!(text.contains("Volume: profile large, fit 1 x weight 2"));
+        assert!(session.explain("mainframe").is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::adjustments::{self, Adjustment};
+use crate::cost::{format_money, CostEstimate, PriceTable};
+use crate::scenario::{Scenario, Weights};
+use serde::Serialize;
+
+const SEVERITIES: &[&str] = &["high", "medium", "low"];
+
+#[derive(Serialize)]
+pub struct ReportEntry {
+    pub name: String,
+    pub score: i32,
+    pub cost: Option<CostEstimate>,
+    /// Adjustments already included in `score`.
+    pub adjustments: Vec<Adjustment>,
+}
+
+/// Risk or anti-pattern of a recommended architecture, with how to avoid it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RiskWarning {
+    pub message: String,
+    pub severity: String,
+    pub mitigation: String,
+}
+
+/// Position of a severity in reports, most severe first.
+pub fn severity_rank(severity: &str) -> usize {
+    SEVERITIES
+        .iter()
+        .position(|s| *s == severity)
+        .unwrap_or(SEVERITIES.len())
+}
+
+/// Ranked recommendations of one run, with cost estimates for the scenario.
+#[derive(Serialize)]
+pub struct Report {
+    pub entries: Vec<ReportEntry>,
+    pub explanations: Vec<String>,
+    pub warnings: Vec<RiskWarning>,
+    pub currency: String,
+    pub budget_monthly: Option<f64>,
+}
+
+impl Report {
+    pub fn new(
+        ranked: &[(&str, i32)],
+        explanations: Vec<String>,
+        scenario: &Scenario,
+        weights: &Weights,
+        prices: &PriceTable,
+    ) -> Self {
+        let volume_tb = scenario.effective_volume_tb();
+        let entries = ranked
+            .iter()
+            .map(|(name, score)| ReportEntry {
+                name: name.to_string(),
+                score: *score,
+                cost: prices.estimate(name, volume_tb),
+                adjustments: adjustments::for_architecture(scenario, weights, name),
+            })
+            .collect();
+        Report {
+            entries,
+            explanations,
+            warnings: vec![],
+            currency: prices.currency.clone(),
+            budget_monthly: scenario.budget_monthly,
+        }
+    }
+
+    pub fn with_warnings(mut self, warnings: Vec<RiskWarning>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    pub fn within_budget(&self, entry: &ReportEntry) -> Option<bool> {
+        match (entry.cost, self.budget_monthly) {
+            (Some(cost), Some(budget)) => Some(cost.monthly <= budget),
+            _ => None,
+        }
+    }
+
+    fn cost_line(&self, entry: &ReportEntry) -> Option<String> {
+        let cost = entry.cost?;
+        let verdict = match self.within_budget(entry) {
+            Some(true) => " (within budget)",
+            Some(false) => " (over budget)",
+            None => "",
+        };
+        Some(format!(
+            "   cost: ~{}/month, 3-year TCO ~{}{}",
+            format_money(cost.monthly, &self.currency),
+            format_money(cost.tco_3y, &self.currency),
+            verdict
+        ))
+    }
+
+    /// Prints the top recommendation, or the whole ranking when `all` is set.
+    pub fn print_recommendations(&self, all: bool) {
+        println!("\n=== Recommendations ===");
+        let shown = if all { self.entries.len() } else { 1 };
+        for entry in self.entries.iter().take(shown) {
+            println!("-> {} (score: {})", entry.name, entry.score);
+            if let Some(line) = self.cost_line(entry) {
+                println!("{}", line);
+            }
+            for adj in &entry.adjustments {
+                println!("   {:+} {}", adj.points, adj.reason);
+            }
+        }
+        if let Some(budget) = self.budget_monthly {
+            println!(
+                "   monthly budget: {}",
+                format_money(budget, &self.currency)
+            );
+        }
+    }
+
+    pub fn print_explanations(&self) {
+        println!("\n=== Explanations ===");
+        for e in &self.explanations {
+            println!("- {}", e);
+        }
+    }
+
+    pub fn print_warnings(&self) {
+        if self.warnings.is_empty() {
+            return;
+        }
+        println!("\n=== Warnings ===");
+        for w in &self.warnings {
+            println!("! [{}] {}", w.severity, w.message);
+            println!("  mitigation: {}", w.mitigation);
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report is serializable")
+    }
+
+    pub fn write_json(&self, path: &str) -> Result<(), String> {
+        std::fs::write(path, self.to_json()).map_err(|e| format!("{}: {}", path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_includes_warnings() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            ..Scenario::default()
+        };
+        let report = Report::new(
+            &[("Hadoop/Spark", 120)],
+            vec!["Hadoop chosen.".to_string()],
+            &scenario,
+            &Weights::default(),
+            &PriceTable::default(),
+        )
+        .with_warnings(vec![RiskWarning {
+            message: "latency risk".to_string(),
+            severity: "high".to_string(),
+            mitigation: "use streaming".to_string(),
+        }]);
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["entries"][0]["name"], "Hadoop/Spark");
+        assert_eq!(json["warnings"][0]["severity"], "high");
+        assert_eq!(severity_rank("medium"), 1);
+    }
+}
@@ -0,0 +1,334 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// Representative sizes used when the volume is given as a category only.
+const SMALL_TB: f64 = 0.5;
+const MEDIUM_TB: f64 = 20.0;
+const LARGE_TB: f64 = 250.0;
+
+/// User answers describing the scenario to evaluate.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Scenario {
+    pub volume: String,
+    pub workload: String,
+    pub sla: String,
+    pub budget: String,
+    pub observability: String,
+    #[serde(default)]
+    pub volume_tb: Option<f64>,
+    #[serde(default)]
+    pub budget_monthly: Option<f64>,
+    /// Regulatory regimes such as "gdpr", "hipaa" or "pci".
+    #[serde(default)]
+    pub compliance: Vec<String>,
+    /// Region the data must stay in, e.g. "eu".
+    #[serde(default)]
+    pub residency: Option<String>,
+    /// End-to-end latency target in milliseconds.
+    #[serde(default)]
+    pub latency_ms: Option<f64>,
+    /// Availability target in percent, e.g. 99.95.
+    #[serde(default)]
+    pub availability: Option<f64>,
+    #[serde(default)]
+    pub team_size: Option<u32>,
+    /// Skills present in the team, e.g. "jvm", "python", "sql", "kubernetes".
+    #[serde(default)]
+    pub skills: Vec<String>,
+    /// Operational maturity: "low", "medium" or "high".
+    #[serde(default)]
+    pub ops_maturity: Option<String>,
+    /// How data is queried: "olap", "lookup", "search", "adhoc".
+    #[serde(default)]
+    pub access_patterns: Vec<String>,
+    /// Where data comes from: "oltp", "saas", "files", "iot", "logs".
+    #[serde(default)]
+    pub sources: Vec<String>,
+    /// ML needs: "training", "deep-learning", "inference", "features",
+    /// "retrieval", "experiments".
+    #[serde(default)]
+    pub ml_needs: Vec<String>,
+    /// Data-quality requirement: "low", "medium" or "high".
+    #[serde(default)]
+    pub data_quality: Option<String>,
+    /// How often schemas change: "low", "medium" or "high".
+    #[serde(default)]
+    pub schema_volatility: Option<String>,
+    /// Update/delete needs: "none", "occasional" or "frequent".
+    #[serde(default)]
+    pub updates: Option<String>,
+    /// Security posture: any of "multi-tenant", "pii", "zero-trust".
+    #[serde(default)]
+    pub security: Vec<String>,
+    /// Fuzzy mode: typed membership vectors per dimension, e.g.
+    /// {"volume": {"medium": 0.6, "large": 0.4}}.
+    #[serde(default)]
+    pub memberships: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+impl Scenario {
+    pub fn as_tuple(&self) -> (&str, &str, &str, &str, &str) {
+        (
+            self.volume.as_str(),
+            self.workload.as_str(),
+            self.sla.as_str(),
+            self.budget.as_str(),
+            self.observability.as_str(),
+        )
+    }
+
+    /// Volume in TB, falling back to a representative size for the category.
+    pub fn effective_volume_tb(&self) -> f64 {
+        self.volume_tb.unwrap_or(match self.volume.as_str() {
+            "small" => SMALL_TB,
+            "large" => LARGE_TB,
+            _ => MEDIUM_TB,
+        })
+    }
+
+    /// Data-quality requirement; without an explicit answer, high
+    /// observability implies high data quality coverage.
+    pub fn data_quality_level(&self) -> &str {
+        match &self.data_quality {
+            Some(level) => level,
+            None if self.observability == "high" => "high",
+            None => "low",
+        }
+    }
+
+    pub fn schema_volatility_level(&self) -> &str {
+        self.schema_volatility.as_deref().unwrap_or("low")
+    }
+
+    pub fn updates_level(&self) -> &str {
+        self.updates.as_deref().unwrap_or("none")
+    }
+}
+
+/// Criteria weights, in the order expected by `similarity_score`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Weights {
+    pub sla: i32,
+    pub budget: i32,
+    pub volume: i32,
+    pub workload: i32,
+    pub observability: i32,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights {
+            sla: 5,
+            budget: 3,
+            volume: 2,
+            workload: 1,
+            observability: 4,
+        }
+    }
+}
+
+impl Weights {
+    pub fn as_tuple(&self) -> (i32, i32, i32, i32, i32) {
+        (
+            self.sla,
+            self.budget,
+            self.volume,
+            self.workload,
+            self.observability,
+        )
+    }
+
+    pub fn sum(&self) -> i32 {
+        self.sla + self.budget + self.volume + self.workload + self.observability
+    }
+
+    pub fn as_array(&self) -> [i32; 5] {
+        [
+            self.sla,
+            self.budget,
+            self.volume,
+            self.workload,
+            self.observability,
+        ]
+    }
+
+    pub fn from_array(values: [i32; 5]) -> Self {
+        let [sla, budget, volume, workload, observability] = values;
+        Weights {
+            sla,
+            budget,
+            volume,
+            workload,
+            observability,
+        }
+    }
+}
+
+/// Named weight set of one participant in a group decision.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stakeholder {
+    pub name: String,
+    pub weights: Weights,
+}
+
+/// Contents of a `--scenario` file: the inputs plus either one weight set
+/// or the weight sets of several stakeholders.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScenarioFile {
+    #[serde(flatten)]
+    pub scenario: Scenario,
+    #[serde(default)]
+    pub weights: Option<Weights>,
+    #[serde(default)]
+    pub stakeholders: Vec<Stakeholder>,
+}
+
+impl ScenarioFile {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+}
+
+pub fn volume_category(tb: f64) -> &'static str {
+    if tb < 1.0 {
+        "small"
+    } else if tb < 100.0 {
+        "medium"
+    } else {
+        "large"
+    }
+}
+
+/// Parses "small/medium/large" or a size such as "40", "40tb", "500gb", "2pb".
+/// Numeric sizes are mapped to a category for the rule engine.
+pub fn parse_volume(input: &str) -> (String, Option<f64>) {
+    let s = input.trim().to_lowercase();
+    let (digits, factor) = if let Some(v) = s.strip_suffix("pb") {
+        (v, 1024.0)
+    } else if let Some(v) = s.strip_suffix("gb") {
+        (v, 1.0 / 1024.0)
+    } else if let Some(v) = s.strip_suffix("tb") {
+        (v, 1.0)
+    } else {
+        (s.as_str(), 1.0)
+    };
+    match digits.trim().parse::<f64>() {
+        Ok(v) if v >= 0.0 => {
+            let tb = v * factor;
+            (volume_category(tb).to_string(), Some(tb))
+        }
+        _ => (s, None),
+    }
+}
+
+/// Splits a comma- or space-separated answer into lowercase items.
+pub fn parse_list(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Splits a comma-separated answer into names, keeping spaces and case.
+pub fn parse_names(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Parses "low/high" or a monthly amount such as "15000", "$15,000" or "15k".
+/// Amounts below `high_threshold` count as a low budget for the rule engine.
+pub fn parse_budget(input: &str, high_threshold: f64) -> (String, Option<f64>) {
+    let s = input.trim().to_lowercase();
+    let cleaned: String = s.chars().filter(|c| *c != '$' && *c != ',').collect();
+    let (digits, factor) = match cleaned.strip_suffix('k') {
+        Some(v) => (v.to_string(), 1000.0),
+        None => (cleaned, 1.0),
+    };
+    match digits.trim().parse::<f64>() {
+        Ok(v) if v >= 0.0 => {
+            let amount = v * factor;
+            let category = if amount < high_threshold {
+                "low"
+            } else {
+                "high"
+            };
+            (category.to_string(), Some(amount))
+        }
+        _ => (s, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_volume_units() {
+        assert_eq!(parse_volume("large"), ("large".to_string(), None));
+        assert_eq!(parse_volume("40tb"), ("medium".to_string(), Some(40.0)));
+        assert_eq!(parse_volume("512gb"), ("small".to_string(), Some(0.5)));
+        assert_eq!(parse_volume("2pb"), ("large".to_string(), Some(2048.0)));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            parse_list(" GDPR, pci hipaa "),
+            vec!["gdpr", "pci", "hipaa"]
+        );
+        assert!(parse_list("").is_empty());
+        assert_eq!(
+            parse_names(" SLA owner, Finance ,"),
+            vec!["SLA owner", "Finance"]
+        );
+    }
+
+    #[test]
+    fn test_parse_budget_amounts() {
+        assert_eq!(parse_budget("high", 20000.0), ("high".to_string(), None));
+        assert_eq!(
+            parse_budget("$15,000", 20000.0),
+            ("low".to_string(), Some(15000.0))
+        );
+        assert_eq!(
+            parse_budget("25k", 20000.0),
+            ("high".to_string(), Some(25000.0))
+        );
+    }
+
+    #[test]
+    fn test_data_quality_follows_observability() {
+        let mut scenario = Scenario {
+            observability: "high".to_string(),
+            ..Scenario::default()
+        };
+        assert_eq!(scenario.data_quality_level(), "high");
+        scenario.data_quality = Some("medium".to_string());
+        assert_eq!(scenario.data_quality_level(), "medium");
+        assert_eq!(Scenario::default().data_quality_level(), "low");
+    }
+
+    #[test]
+    fn test_scenario_file_with_stakeholders() {
+        let file: ScenarioFile = serde_json::from_str(
+            r#"{
+                "volume": "large", "workload": "mixed", "sla": "high",
+                "budget": "low", "observability": "medium",
+                "compliance": ["gdpr"],
+                "stakeholders": [
+                    {"name": "finance", "weights": {"sla": 1, "budget": 9, "volume": 2, "workload": 1, "observability": 1}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(file.scenario.volume, "large");
+        assert_eq!(file.scenario.compliance, vec!["gdpr"]);
+        assert_eq!(file.weights, None);
+        assert_eq!(file.stakeholders[0].weights.as_array(), [1, 9, 2, 1, 1]);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::formats::{is_event_stream, is_open_format_storage, is_warehouse};
+use crate::scenario::Scenario;
+use crate::SecurityControl;
+
+pub const POSTURES: &[&str] = &["multi-tenant", "pii", "zero-trust"];
+
+/// Recommendations that hold or move data and therefore carry security controls.
+pub fn is_data_platform(name: &str) -> bool {
+    is_open_format_storage(name) || is_warehouse(name) || is_event_stream(name)
+}
+
+pub fn unknown_postures(scenario: &Scenario) -> Vec<&str> {
+    scenario
+        .security
+        .iter()
+        .map(|p| p.as_str())
+        .filter(|p| !POSTURES.contains(p))
+        .collect()
+}
+
+pub fn print(controls: &[SecurityControl]) {
+    if controls.is_empty() {
+        return;
+    }
+    println!("\n=== Security Controls ===");
+    let mut platform = "";
+    for SecurityControl(control, p, how) in controls {
+        if *p != platform {
+            println!("{}:", p);
+            platform = p;
+        }
+        println!("- {}: {}", control, how);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_platforms_and_postures() {
+        assert!(is_data_platform("Cloud DW (BigQuery/Snowflake)"));
+        assert!(!is_data_platform("FinOps Dashboards"));
+        let scenario = Scenario {
+            security: vec!["pii".to_string(), "airgap".to_string()],
+            ..Scenario::default()
+        };
+        assert_eq!(unknown_postures(&scenario), vec!["airgap"]);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::adjustments::Adjustment;
+use crate::catalog::ACCESS_PATTERNS;
+use crate::scenario::Scenario;
+
+// Points for a serving architecture that answers / ignores the requested access patterns.
+const PATTERN_MATCH: i32 = 30;
+const PATTERN_MISMATCH: i32 = -20;
+
+pub fn has_access_patterns(scenario: &Scenario) -> bool {
+    !scenario.access_patterns.is_empty()
+}
+
+/// Favours serving architectures built for the requested access patterns.
+pub fn adjust(scenario: &Scenario, name: &str) -> Vec<Adjustment> {
+    let Some((_, served)) = ACCESS_PATTERNS.iter().find(|(n, _)| *n == name) else {
+        return vec![];
+    };
+    if !has_access_patterns(scenario) {
+        return vec![];
+    }
+
+    let matched: Vec<&str> = scenario
+        .access_patterns
+        .iter()
+        .map(|p| p.as_str())
+        .filter(|p| served.contains(p))
+        .collect();
+    if matched.is_empty() {
+        vec![Adjustment::new(
+            PATTERN_MISMATCH,
+            format!(
+                "access: built for {}, not for {}",
+                served.join("/"),
+                scenario.access_patterns.join("/")
+            ),
+        )]
+    } else {
+        vec![Adjustment::new(
+            PATTERN_MATCH * matched.len() as i32,
+            format!("access: serves {} queries", matched.join("/")),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scenario(patterns: &[&str]) -> Scenario {
+        Scenario {
+            access_patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            ..Scenario::default()
+        }
+    }
+
+    #[test]
+    fn test_matching_serving_layer_gets_bonus() {
+        let adj = adjust(
+            &scenario(&["olap", "adhoc"]),
+            "Cloud DW (BigQuery/Snowflake)",
+        );
+        assert_eq!(adj[0].points, 2 * PATTERN_MATCH);
+    }
+
+    #[test]
+    fn test_mismatching_serving_layer_gets_penalty() {
+        let adj = adjust(
+            &scenario(&["search"]),
+            "Key-Value Store (Redis/Cassandra/DynamoDB)",
+        );
+        assert_eq!(adj[0].points, PATTERN_MISMATCH);
+        assert!(adjust(&scenario(&[]), "Lakehouse").is_empty());
+    }
+}
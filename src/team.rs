@@ -0,0 +1,147 @@
+use crate::adjustments::Adjustment;
+use crate::catalog::OPERATIONS;
+use crate::scenario::Scenario;
+
+pub const SKILLS: &[&str] = &["jvm", "python", "sql", "kubernetes"];
+
+// Points lost per level of operational load the team cannot absorb, and per missing skill.
+const OPS_PENALTY: i32 = 15;
+const SKILL_PENALTY: i32 = 10;
+
+pub fn team_size_class(size: u32) -> &'static str {
+    if size < 5 {
+        "small"
+    } else if size < 15 {
+        "medium"
+    } else {
+        "large"
+    }
+}
+
+fn level(class: &str) -> i32 {
+    match class {
+        "low" | "small" => 0,
+        "high" | "large" => 2,
+        _ => 1,
+    }
+}
+
+/// Operational load (0-4) the team can carry: ops maturity plus team size.
+/// Unknown answers count as medium so that a single answer still matters.
+pub fn ops_capacity(scenario: &Scenario) -> i32 {
+    let maturity = scenario.ops_maturity.as_deref().map(level).unwrap_or(1);
+    let size = scenario
+        .team_size
+        .map(|n| level(team_size_class(n)))
+        .unwrap_or(1);
+    maturity + size
+}
+
+pub fn has_team_input(scenario: &Scenario) -> bool {
+    scenario.team_size.is_some() || scenario.ops_maturity.is_some() || !scenario.skills.is_empty()
+}
+
+/// Skills from the catalog's skill list that the team did not report.
+pub fn missing_skills(scenario: &Scenario) -> Vec<&'static str> {
+    if scenario.skills.is_empty() {
+        return vec![];
+    }
+    SKILLS
+        .iter()
+        .copied()
+        .filter(|s| !scenario.skills.iter().any(|k| k == s))
+        .collect()
+}
+
+fn describe_team(scenario: &Scenario) -> String {
+    let mut parts = Vec::new();
+    if let Some(size) = scenario.team_size {
+        parts.push(format!("{}-person team", size));
+    }
+    if let Some(maturity) = &scenario.ops_maturity {
+        parts.push(format!("{} ops maturity", maturity));
+    }
+    parts.join(", ")
+}
+
+/// Penalties for architectures whose operational load or skill needs exceed the team.
+pub fn adjust(scenario: &Scenario, name: &str) -> Vec<Adjustment> {
+    let mut result = Vec::new();
+    let Some((_, load, required)) = OPERATIONS.iter().find(|(n, _, _)| *n == name) else {
+        return result;
+    };
+
+    if scenario.team_size.is_some() || scenario.ops_maturity.is_some() {
+        let capacity = ops_capacity(scenario);
+        let excess = *load - capacity;
+        if excess > 0 {
+            result.push(Adjustment::new(
+                -excess * OPS_PENALTY,
+                format!(
+                    "operations: load {} exceeds team capacity {} ({}); prefer a managed service",
+                    load,
+                    capacity,
+                    describe_team(scenario)
+                ),
+            ));
+        }
+    }
+
+    let missing: Vec<_> = missing_skills(scenario)
+        .into_iter()
+        .filter(|s| required.contains(s))
+        .collect();
+    if !missing.is_empty() {
+        result.push(Adjustment::new(
+            -(missing.len() as i32) * SKILL_PENALTY,
+            format!("skills: team lacks {}", missing.join(", ")),
+        ));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_team() -> Scenario {
+        Scenario {
+            team_size: Some(3),
+            skills: vec!["python".to_string(), "sql".to_string()],
+            ops_maturity: Some("low".to_string()),
+            ..Scenario::default()
+        }
+    }
+
+    #[test]
+    fn test_capacity_from_size_and_maturity() {
+        assert_eq!(ops_capacity(&small_team()), 0);
+        let platform_team = Scenario {
+            team_size: Some(20),
+            ops_maturity: Some("high".to_string()),
+            ..Scenario::default()
+        };
+        assert_eq!(ops_capacity(&platform_team), 4);
+        assert_eq!(ops_capacity(&Scenario::default()), 2);
+        let unknown = Scenario {
+            ops_maturity: Some("expert".to_string()),
+            ..Scenario::default()
+        };
+        assert_eq!(ops_capacity(&unknown), 2);
+    }
+
+    #[test]
+    fn test_self_managed_hadoop_penalized_for_small_team() {
+        let adjustments = adjust(&small_team(), "Hadoop/Spark");
+        assert_eq!(adjustments.len(), 2);
+        assert_eq!(adjustments[0].points, -45);
+        assert!(adjustments[0].reason.contains("3-person team"));
+        assert_eq!(adjustments[1].reason, "skills: team lacks jvm");
+    }
+
+    #[test]
+    fn test_managed_service_not_penalized() {
+        let adjustments = adjust(&small_team(), "Cloud DW (BigQuery/Snowflake)");
+        assert!(adjustments.is_empty());
+    }
+}
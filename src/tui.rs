@@ -0,0 +1,364 @@
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::ahp::CRITERIA;
+use crate::questionnaire::VALUES;
+use crate::ranking::RankingStrategy;
+use crate::repl::explanation;
+use crate::scenario::{Scenario, Weights};
+use crate::{Recommendation, MULTI_TOP};
+
+// Sliders run from 0 (criterion ignored) to this weight.
+const MAX_WEIGHT: i32 = 10;
+// Rows of the input panel: five selectable inputs, then five weight sliders.
+const ROWS: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Focus {
+    Inputs,
+    Ranking,
+}
+
+/// State of the full-screen session: current inputs, weights and selections.
+/// Rankings and the explanation are cached, so they are only evaluated again
+/// when an input or the highlighted architecture changes, not on every frame.
+pub struct App<'a> {
+    scenario: Scenario,
+    weights: Weights,
+    strategy: &'a dyn RankingStrategy,
+    focus: Focus,
+    row: usize,
+    selected: usize,
+    ranked: Vec<(&'static str, i32)>,
+    strict: Vec<Recommendation>,
+    explanation: Vec<String>,
+}
+
+impl<'a> App<'a> {
+    pub fn new(scenario: Scenario, weights: Weights, strategy: &'a dyn RankingStrategy) -> Self {
+        let mut app = App {
+            scenario,
+            weights,
+            strategy,
+            focus: Focus::Inputs,
+            row: 0,
+            selected: 0,
+            ranked: Vec::new(),
+            strict: Vec::new(),
+            explanation: Vec::new(),
+        };
+        app.evaluate();
+        app
+    }
+
+    fn value_mut(&mut self, dimension: usize) -> &mut String {
+        match dimension {
+            0 => &mut self.scenario.sla,
+            1 => &mut self.scenario.budget,
+            2 => &mut self.scenario.volume,
+            3 => &mut self.scenario.workload,
+            _ => &mut self.scenario.observability,
+        }
+    }
+
+    fn values(&self) -> [&str; 5] {
+        [
+            &self.scenario.sla,
+            &self.scenario.budget,
+            &self.scenario.volume,
+            &self.scenario.workload,
+            &self.scenario.observability,
+        ]
+    }
+
+    /// Moves the highlighted row's value or weight one step left or right.
+    fn step(&mut self, delta: i32) {
+        if self.row < 5 {
+            let options = VALUES[self.row];
+            let value = self.value_mut(self.row);
+            let index = options.iter().position(|o| o == value).map_or(0, |i| {
+                (i as i32 + delta).rem_euclid(options.len() as i32) as usize
+            });
+            *value = options[index].to_string();
+            // A category replaces a typed size or monthly amount.
+            match self.row {
+                1 => self.scenario.budget_monthly = None,
+                2 => self.scenario.volume_tb = None,
+                _ => {}
+            }
+        } else {
+            let mut weights = self.weights.as_array();
+            let w = &mut weights[self.row - 5];
+            *w = (*w + delta).clamp(0, MAX_WEIGHT);
+            self.weights = Weights::from_array(weights);
+        }
+    }
+
+    /// Ranks the current inputs again, then explains the highlighted entry.
+    fn evaluate(&mut self) {
+        self.ranked = crate::evaluate_multi_with(&self.scenario, &self.weights, self.strategy);
+        self.strict = crate::evaluate_strict(&self.scenario, &self.weights).0;
+        self.selected = self.selected.min(self.ranked.len().saturating_sub(1));
+        self.explain();
+    }
+
+    fn explain(&mut self) {
+        self.explanation = match self.ranked.get(self.selected) {
+            Some((name, _)) => explanation(&self.scenario, &self.weights, name),
+            None => Vec::new(),
+        };
+    }
+
+    /// Handles one key press; returns false when the session should end.
+    pub fn handle(&mut self, key: KeyCode) -> bool {
+        let ranked = self.ranked.len();
+        let selected = self.selected;
+        match (key, self.focus) {
+            (KeyCode::Char('q') | KeyCode::Esc, _) => return false,
+            (KeyCode::Tab | KeyCode::BackTab, Focus::Inputs) => self.focus = Focus::Ranking,
+            (KeyCode::Tab | KeyCode::BackTab, Focus::Ranking) => self.focus = Focus::Inputs,
+            (KeyCode::Up | KeyCode::Char('k'), Focus::Inputs) => {
+                self.row = (self.row + ROWS - 1) % ROWS
+            }
+            (KeyCode::Down | KeyCode::Char('j'), Focus::Inputs) => self.row = (self.row + 1) % ROWS,
+            (KeyCode::Left | KeyCode::Char('h'), Focus::Inputs) => {
+                self.step(-1);
+                self.evaluate();
+            }
+            (KeyCode::Right | KeyCode::Char('l'), Focus::Inputs) => {
+                self.step(1);
+                self.evaluate();
+            }
+            (KeyCode::Up | KeyCode::Char('k'), Focus::Ranking) => {
+                self.selected = self.selected.saturating_sub(1)
+            }
+            (KeyCode::Down | KeyCode::Char('j'), Focus::Ranking) => {
+                self.selected = (self.selected + 1).min(ranked.saturating_sub(1))
+            }
+            _ => {}
+        }
+        if self.selected != selected {
+            self.explain();
+        }
+        true
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [inputs, ranking, right] = Layout::horizontal([
+            Constraint::Percentage(30),
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+        ])
+        .areas(main);
+        let [strict, explain] =
+            Layout::vertical([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(right);
+
+        self.render_inputs(frame, inputs);
+        self.render_ranking(frame, ranking);
+        self.render_strict(frame, strict);
+        self.render_explanation(frame, explain);
+        frame.render_widget(
+            Paragraph::new(
+                "Up/Down select  Left/Right change  Tab switch to ranking/inputs  q quit",
+            ),
+            help,
+        );
+    }
+
+    fn block(&self, title: &str, focused: bool) -> Block<'static> {
+        let block = Block::bordered().title(title.to_string());
+        if focused {
+            block.border_style(Style::new().add_modifier(Modifier::BOLD))
+        } else {
+            block
+        }
+    }
+
+    fn render_inputs(&self, frame: &mut Frame, area: Rect) {
+        let values = self.values();
+        let weights = self.weights.as_array();
+        let mut items: Vec<ListItem> = (0..5)
+            .map(|i| ListItem::new(format!("{:<16} < {} >", CRITERIA[i], values[i])))
+            .collect();
+        items.extend((0..5).map(|i| {
+            let w = weights[i].clamp(0, MAX_WEIGHT) as usize;
+            let bar = "#".repeat(w) + &"-".repeat(MAX_WEIGHT as usize - w);
+            ListItem::new(format!(
+                "{:<16} [{}] {}",
+                format!("{} w", CRITERIA[i]),
+                bar,
+                weights[i]
+            ))
+        }));
+        let focused = self.focus == Focus::Inputs;
+        let list = List::new(items)
+            .block(self.block(" Inputs and weights ", focused))
+            .highlight_symbol("> ")
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(focused.then_some(self.row));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn render_ranking(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .ranked
+            .iter()
+            .enumerate()
+            .map(|(i, (name, score))| {
+                let item = ListItem::new(format!("{:>2}. {:>3}  {}", i + 1, score, name));
+                if i < MULTI_TOP {
+                    item.style(Style::new().add_modifier(Modifier::BOLD))
+                } else {
+                    item
+                }
+            })
+            .collect();
+        let title = format!(" Multi ranking ({}) ", self.strategy.name());
+        let list = List::new(items)
+            .block(self.block(&title, self.focus == Focus::Ranking))
+            .highlight_symbol("> ")
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn render_strict(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = if self.strict.is_empty() {
+            vec![Line::from("(no rule matches)")]
+        } else {
+            self.strict
+                .iter()
+                .map(|Recommendation(name, score)| Line::from(format!("{} ({})", name, score)))
+                .collect()
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(self.block(" Strict matches ", false)),
+            area,
+        );
+    }
+
+    fn render_explanation(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = match self.ranked.get(self.selected) {
+            Some((name, score)) => {
+                let mut lines = vec![Line::from(format!("{} (score {})", name, score))];
+                lines.extend(self.explanation.iter().map(|l| Line::from(l.as_str())));
+                lines
+            }
+            None => vec![],
+        };
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(self.block(" Explanation ", false)),
+            area,
+        );
+    }
+}
+
+/// Scenario to start from when no `--scenario` file is given: the first
+/// value of every input.
+pub fn starting_scenario() -> Scenario {
+    let [sla, budget, volume, workload, observability] = VALUES.map(|v| v[0].to_string());
+    Scenario {
+        volume,
+        workload,
+        sla,
+        budget,
+        observability,
+        ..Scenario::default()
+    }
+}
+
+/// Runs the full-screen session until the user quits.
+pub fn run(app: &mut App) -> io::Result<()> {
+    let mut terminal = ratatui::try_init()?;
+    let result = (|| loop {
+        terminal.draw(|frame| app.render(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle(key.code) {
+                return Ok(());
+            }
+        }
+    })();
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ranking::Additive;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_keys_change_inputs_and_weights() {
+        let mut app = App::new(starting_scenario(), Weights::default(), &Additive);
+        app.handle(KeyCode::Right);
+        assert_eq!(app.scenario.sla, "high");
+        app.handle(KeyCode::Right);
+        assert_eq!(app.scenario.sla, "low");
+        app.handle(KeyCode::Up);
+        assert_eq!(app.row, 9);
+        for _ in 0..20 {
+            app.handle(KeyCode::Right);
+        }
+        assert_eq!(app.weights.observability, MAX_WEIGHT);
+        app.handle(KeyCode::Tab);
+        app.handle(KeyCode::Down);
+        assert_eq!(app.selected, 1);
+        let name = app.ranked[1].0;
+        assert_eq!(
+            app.explanation,
+            explanation(&app.scenario, &app.weights, name)
+        );
+        assert!(!app.handle(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn test_budget_slider_drops_monthly_amount() {
+        let scenario = Scenario {
+            budget: "high".to_string(),
+            budget_monthly: Some(20_000.0),
+            ..starting_scenario()
+        };
+        let mut app = App::new(scenario, Weights::default(), &Additive);
+        app.handle(KeyCode::Down);
+        app.handle(KeyCode::Right);
+        assert_eq!(app.scenario.budget, "low");
+        assert_eq!(app.scenario.budget_monthly, None);
+    }
+
+    #[test]
+    fn test_render_shows_ranking_and_explanation() {
+        let scenario = Scenario {
+            volume: "large".to_string(),
+            workload: "mixed".to_string(),
+            sla: "high".to_string(),
+            budget: "high".to_string(),
+            observability: "high".to_string(),
+            ..Scenario::default()
+        };
+        let app = App::new(scenario, Weights::default(), &Additive);
+        let mut terminal = Terminal::new(TestBackend::new(160, 30)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("  Lakehouse"));
+        assert!(text.contains("Lakehouse (score"));
+        assert!(text.contains("Strict matches"));
+    }
+}